@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+/// What a part of a puzzle hands back. Most days are a number, a few (day5's
+/// crate tops, day10's CRT) are text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Anything too big for an `i64` is kept as its digits, rather than
+/// wrapped round to a different number
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Number(n),
+                        Err(_) => Answer::Text(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("2713310158", Answer::from(2713310158_usize).to_string());
+        assert_eq!("CMZ", Answer::from("CMZ").to_string());
+    }
+
+    #[test]
+    fn test_too_big() {
+        assert_eq!(Answer::Number(i64::MAX), Answer::from(i64::MAX as u64));
+        assert_eq!(
+            Answer::Text("18446744073709551615".into()),
+            Answer::from(u64::MAX)
+        );
+        assert_eq!(u64::MAX.to_string(), Answer::from(u64::MAX).to_string());
+    }
+}
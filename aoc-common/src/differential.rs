@@ -0,0 +1,247 @@
+//! Checking a fast implementation against a slow one that's easier to
+//! trust, on lots of made-up inputs. Days hand theirs out through
+//! [`Solution::differentials`](crate::Solution::differentials).
+
+use std::{
+    fmt::Display,
+    ops::Range,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use crate::{generate::Generator, generate::Rng, Answer};
+
+type Implementation = Box<dyn Fn(&str) -> eyre::Result<Answer>>;
+
+/// Two ways of working out the same thing, both straight from the input
+/// text
+pub struct Differential {
+    pub name: &'static str,
+    reference: Implementation,
+    fast: Implementation,
+    /// Generator knobs that keep inputs small enough for the reference
+    settings: Vec<(&'static str, &'static str)>,
+}
+
+/// What one side made of an input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(Answer),
+    Error(String),
+    Panic(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Error(e) => write!(f, "error: {e}"),
+            Outcome::Panic(msg) => write!(f, "panicked: {msg}"),
+        }
+    }
+}
+
+/// An input the two sides disagree on
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub input: String,
+    pub reference: Outcome,
+    pub fast: Outcome,
+}
+
+fn run(implementation: &Implementation, input: &str) -> Outcome {
+    match catch_unwind(AssertUnwindSafe(|| implementation(input))) {
+        Ok(Ok(answer)) => Outcome::Answer(answer),
+        Ok(Err(e)) => Outcome::Error(e.to_string()),
+        Err(payload) => {
+            let msg = match (
+                payload.downcast_ref::<&str>(),
+                payload.downcast_ref::<String>(),
+            ) {
+                (Some(s), _) => s.to_string(),
+                (_, Some(s)) => s.clone(),
+                _ => "with something that isn't a message".to_string(),
+            };
+            Outcome::Panic(msg)
+        }
+    }
+}
+
+impl Differential {
+    pub fn new<A, B>(
+        name: &'static str,
+        reference: impl Fn(&str) -> eyre::Result<A> + 'static,
+        fast: impl Fn(&str) -> eyre::Result<B> + 'static,
+    ) -> Self
+    where
+        A: Into<Answer>,
+        B: Into<Answer>,
+    {
+        Self {
+            name,
+            reference: Box::new(move |input| reference(input).map(Into::into)),
+            fast: Box::new(move |input| fast(input).map(Into::into)),
+            settings: Vec::new(),
+        }
+    }
+
+    /// Sets a knob on the generator before making any inputs
+    pub fn with_setting(mut self, name: &'static str, value: &'static str) -> Self {
+        self.settings.push((name, value));
+        self
+    }
+
+    pub fn settings(&self) -> &[(&'static str, &'static str)] {
+        &self.settings
+    }
+
+    /// Applies this differential's settings to `generator`
+    pub fn configure(&self, generator: &mut dyn Generator) -> eyre::Result<()> {
+        for &(name, value) in &self.settings {
+            generator.set_param(name, value)?;
+        }
+        Ok(())
+    }
+
+    /// What the reference and then the fast side make of `input`. A panic
+    /// counts as an outcome, not a failure of the check.
+    pub fn compare(&self, input: &str) -> (Outcome, Outcome) {
+        (run(&self.reference, input), run(&self.fast, input))
+    }
+
+    /// Generates an input from each seed and compares both sides on it.
+    /// Of the inputs they disagree on, the shortest is the easiest to look
+    /// at, so that's the one that comes back.
+    ///
+    /// The generator should already be [`configure`](Differential::configure)d,
+    /// with anything else on top.
+    pub fn find_mismatch(
+        &self,
+        generator: &dyn Generator,
+        seeds: Range<u64>,
+    ) -> eyre::Result<Option<Mismatch>> {
+        let mut smallest: Option<Mismatch> = None;
+        for seed in seeds {
+            let input = generator.generate(&mut Rng::new(seed))?;
+            if smallest
+                .as_ref()
+                .is_some_and(|m| m.input.len() <= input.len())
+            {
+                continue;
+            }
+            let (reference, fast) = self.compare(&input);
+            if reference != fast {
+                smallest = Some(Mismatch {
+                    seed,
+                    input,
+                    reference,
+                    fast,
+                });
+            }
+        }
+        Ok(smallest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eyre::{bail, ensure};
+
+    use super::*;
+    use crate::{parse_param, Param};
+
+    /// A list of numbers, up to `most` of them
+    struct Numbers {
+        most: usize,
+    }
+
+    impl Generator for Numbers {
+        fn params(&self) -> Vec<Param> {
+            vec![Param::new("most", "most numbers", self.most)]
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+            match name {
+                "most" => self.most = parse_param(name, value)?,
+                _ => bail!("no parameter {name:?}"),
+            }
+            Ok(())
+        }
+
+        fn generate(&self, rng: &mut Rng) -> eyre::Result<String> {
+            ensure!(self.most > 0, "at least one number");
+            let n = 1 + rng.index(self.most);
+            Ok((0..n).map(|_| format!("{}\n", rng.range(0..=9))).collect())
+        }
+    }
+
+    fn numbers(input: &str) -> Vec<i64> {
+        input.lines().map(|l| l.parse().unwrap()).collect()
+    }
+
+    /// Sums the numbers one at a time, or all but the last one when there
+    /// are more than five
+    fn sums() -> Differential {
+        Differential::new(
+            "sum",
+            |input| Ok(numbers(input).iter().sum::<i64>()),
+            |input| {
+                let numbers = numbers(input);
+                let end = if numbers.len() > 5 {
+                    numbers.len() - 1
+                } else {
+                    numbers.len()
+                };
+                Ok(numbers[..end].iter().sum::<i64>())
+            },
+        )
+        .with_setting("most", "12")
+    }
+
+    #[test]
+    fn test_finds_smallest() -> eyre::Result<()> {
+        let diff = sums();
+        let mut generator = Numbers { most: 3 };
+        assert!(diff.find_mismatch(&generator, 0..50)?.is_none());
+
+        diff.configure(&mut generator)?;
+        let mismatch = diff.find_mismatch(&generator, 0..200)?.unwrap();
+        // the smallest an input can be and still disagree
+        assert_eq!(6, mismatch.input.lines().count());
+        let numbers = numbers(&mismatch.input);
+        assert_eq!(
+            Outcome::Answer(Answer::Number(numbers.iter().sum())),
+            mismatch.reference
+        );
+        assert_ne!(mismatch.reference, mismatch.fast);
+        assert_eq!(
+            mismatch.input,
+            generator.generate(&mut Rng::new(mismatch.seed))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let diff = Differential::new(
+            "halves",
+            |input| match input.trim().parse::<i64>()? {
+                n if n % 2 == 0 => Ok(n / 2),
+                n => bail!("{n} is odd"),
+            },
+            |input| Ok(input.trim().parse::<i64>()? / 2),
+        );
+        let (reference, fast) = diff.compare("8");
+        assert_eq!(reference, fast);
+        let (reference, fast) = diff.compare("7");
+        assert_eq!("error: 7 is odd", reference.to_string());
+        assert_eq!("3", fast.to_string());
+
+        let panics = Differential::new(
+            "panics",
+            |_| Ok(1),
+            |_| -> eyre::Result<i64> { panic!("oops") },
+        );
+        assert_eq!(Outcome::Panic("oops".to_string()), panics.compare("").1);
+    }
+}
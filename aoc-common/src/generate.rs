@@ -0,0 +1,112 @@
+//! Made-up puzzle inputs, for trying days on inputs far bigger than the
+//! real ones. Each day's [`Generator`] has knobs for the size of what it
+//! makes, and the same knobs and seed always give the same input.
+
+use std::ops::RangeInclusive;
+
+use crate::Param;
+
+/// splitmix64: small, fast, and happy with any seed, 0 included. Not for
+/// anything that needs real randomness.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// From 0 up to but not including `n`, which can't be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "nothing is below 0");
+        // the bias is at most n / 2^64, which nobody here will notice
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "{lo}..={hi} is empty");
+        let width = hi.abs_diff(lo);
+        match width.checked_add(1) {
+            Some(n) => lo.wrapping_add(self.below(n) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// True `numerator` times out of `denominator`
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Makes up valid input for one day. The knobs work like a day's
+/// [`Param`]s: set by name from text.
+pub trait Generator {
+    /// The knobs, as currently set
+    fn params(&self) -> Vec<Param>;
+
+    /// Changes one of the knobs from [`Generator::params`]
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()>;
+
+    /// Fails when the knobs don't add up to an input the puzzle allows
+    fn generate(&self, rng: &mut Rng) -> eyre::Result<String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        for _ in 0..5 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        assert_ne!(0, Rng::new(0).next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(2022);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let n = rng.range(-3..=3);
+            assert!((-3..=3).contains(&n));
+            seen[(n + 3) as usize] = true;
+            assert!(rng.below(10) < 10);
+        }
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(5, rng.range(5..=5));
+        rng.range(i64::MIN..=i64::MAX);
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!((0..20).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..20).collect::<Vec<_>>(), items);
+        assert!((0..100).all(|_| !rng.chance(0, 3) && rng.chance(3, 3)));
+    }
+}
@@ -0,0 +1,11 @@
+mod answer;
+pub mod differential;
+pub mod generate;
+mod params;
+pub mod parse;
+mod solution;
+pub mod tool;
+
+pub use answer::Answer;
+pub use params::{parse_param, Param};
+pub use solution::{Parsed, Part, Puzzle, Solution};
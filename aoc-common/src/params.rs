@@ -0,0 +1,46 @@
+use std::{fmt::Display, str::FromStr};
+
+use eyre::eyre;
+
+/// A knob on a day that isn't part of the puzzle input, like how many rounds
+/// to play. The value is kept as text so every day can be set the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub value: String,
+}
+
+impl Param {
+    pub fn new(name: &'static str, help: &'static str, value: impl ToString) -> Self {
+        Self {
+            name,
+            help,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Parses the new value for `name`, saying which parameter it was when it
+/// doesn't fit
+pub fn parse_param<T>(name: &str, value: &str) -> eyre::Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| eyre!("bad value {value:?} for {name}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(10, parse_param::<u64>("rounds", "10").unwrap());
+        let err = parse_param::<u64>("rounds", "ten").unwrap_err();
+        assert!(err.to_string().starts_with(r#"bad value "ten" for rounds"#));
+    }
+}
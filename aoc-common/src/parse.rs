@@ -0,0 +1,394 @@
+//! Glue between the days' nom parsers and errors people can read. Parsers
+//! use the [`IResult`] here instead of nom's, and get run through
+//! [`parse_all`], [`parse_lines`] or [`parse_many`], which turn a failure
+//! into a [`ParseError`] pointing at the spot in the input.
+
+use std::fmt::Display;
+
+use nom::{
+    combinator::all_consuming,
+    error::{ContextError, ErrorKind, FromExternalError},
+    Compare, Finish, InputLength, InputTake, Offset, Parser,
+};
+use nom_locate::LocatedSpan;
+
+pub type IResult<I, O> = nom::IResult<I, O, NomError<I>>;
+
+/// What the parser wanted to see instead of what was there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Char(char),
+    Tag(String),
+    Kind(ErrorKind),
+    Context(&'static str),
+    /// From a `map_res` or similar that rejected what it was given
+    Message(String),
+    /// Every branch of an `alt` failed at the same spot
+    Either(Vec<Expected>),
+}
+
+impl Expected {
+    fn describe(&self) -> String {
+        match self {
+            Expected::Char('\n') => "a new line".to_string(),
+            Expected::Char(c) => format!("{c:?}"),
+            Expected::Tag(t) => format!("{t:?}"),
+            Expected::Kind(kind) => describe_kind(kind).to_string(),
+            Expected::Context(c) => c.to_string(),
+            Expected::Message(m) => m.clone(),
+            Expected::Either(options) => options
+                .iter()
+                .map(Expected::describe)
+                .collect::<Vec<_>>()
+                .join(" or "),
+        }
+    }
+}
+
+fn describe_kind(kind: &ErrorKind) -> &str {
+    match kind {
+        ErrorKind::Digit | ErrorKind::Float => "a number",
+        ErrorKind::Alpha => "a letter",
+        ErrorKind::AlphaNumeric => "a letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "a new line",
+        ErrorKind::Eof => "the end of the input",
+        ErrorKind::OneOf => "one of a few characters",
+        ErrorKind::TakeWhile1 => "at least one character",
+        ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "a valid value",
+        kind => kind.description(),
+    }
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Message(m) => write!(f, "{m}"),
+            _ => write!(f, "expected {}", self.describe()),
+        }
+    }
+}
+
+/// nom's error type for the days' parsers. It remembers the furthest point
+/// any branch got to, which is nearly always the mistake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NomError<I> {
+    pub input: I,
+    pub expected: Expected,
+}
+
+impl<I: InputLength> nom::error::ParseError<I> for NomError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            expected: Expected::Kind(kind),
+        }
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Self {
+            input,
+            expected: Expected::Char(c),
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        use std::cmp::Ordering::*;
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            Less => self,
+            Greater => other,
+            Equal => {
+                let mut options = match self.expected {
+                    Expected::Either(options) => options,
+                    expected => vec![expected],
+                };
+                if !options.contains(&other.expected) {
+                    options.push(other.expected);
+                }
+                Self {
+                    input: other.input,
+                    expected: Expected::Either(options),
+                }
+            }
+        }
+    }
+}
+
+/// A context only takes over when nothing inside it got going, otherwise the
+/// inner error is more precise
+impl<I: InputLength> ContextError<I> for NomError<I> {
+    fn add_context(input: I, ctx: &'static str, other: Self) -> Self {
+        match other.expected {
+            Expected::Context(_) => other,
+            _ if other.input.input_len() < input.input_len() => other,
+            _ => Self {
+                expected: Expected::Context(ctx),
+                ..other
+            },
+        }
+    }
+}
+
+impl<I, E: Display> FromExternalError<I, E> for NomError<I> {
+    fn from_external_error(input: I, _: ErrorKind, e: E) -> Self {
+        Self {
+            input,
+            expected: Expected::Message(e.to_string()),
+        }
+    }
+}
+
+/// nom's `tag`, but the error says which text it was after
+pub fn tag<T, I>(t: T) -> impl Fn(I) -> IResult<I, I>
+where
+    T: InputLength + Clone + Display,
+    I: InputTake + InputLength + Compare<T>,
+{
+    move |i: I| {
+        nom::bytes::complete::tag(t.clone())(i).map_err(|e: nom::Err<NomError<I>>| {
+            e.map(|e| NomError {
+                expected: Expected::Tag(t.to_string()),
+                ..e
+            })
+        })
+    }
+}
+
+/// The inputs parsers can run on: plain `&str`, or a `LocatedSpan` that
+/// tracks its own position
+pub trait Input<'a>: Clone + InputLength + From<&'a str> {
+    /// Bytes from the start of `input` to here
+    fn offset_in(&self, input: &'a str) -> usize;
+}
+
+impl<'a> Input<'a> for &'a str {
+    fn offset_in(&self, input: &'a str) -> usize {
+        input.offset(self)
+    }
+}
+
+impl<'a> Input<'a> for LocatedSpan<&'a str> {
+    fn offset_in(&self, _: &'a str) -> usize {
+        self.location_offset()
+    }
+}
+
+/// A parse failure, with where it happened and the line it happened on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Counts from 1
+    pub line: usize,
+    /// Counts characters from 1
+    pub column: usize,
+    pub source_line: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// `offset` is in bytes from the start of `input`
+    pub fn at(input: &str, offset: usize, expected: impl Display) -> Self {
+        let offset = offset.min(input.len());
+        let start = input[..offset].rfind('\n').map_or(0, |n| n + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |n| offset + n);
+        Self {
+            line: input[..start].matches('\n').count() + 1,
+            column: input[start..offset].chars().count() + 1,
+            source_line: input[start..end].trim_end_matches('\r').to_string(),
+            expected: expected.to_string(),
+        }
+    }
+}
+
+/// ```text
+/// line 2, column 6: expected a number
+///  2 | addx x
+///    |      ^
+/// ```
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, " {} | {}", self.line, self.source_line)?;
+        write!(f, " {gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn run<'a, I, T>(input: &'a str, parser: impl Parser<I, T, NomError<I>>) -> Result<T, NomError<I>>
+where
+    I: Input<'a>,
+{
+    all_consuming(parser)(I::from(input))
+        .finish()
+        .map(|(_, t)| t)
+}
+
+/// Runs `parser` over the whole of `input`, which it has to use up
+pub fn parse_all<'a, I, T>(
+    input: &'a str,
+    parser: impl Parser<I, T, NomError<I>>,
+) -> Result<T, ParseError>
+where
+    I: Input<'a>,
+{
+    run(input, parser).map_err(|e| ParseError::at(input, e.input.offset_in(input), e.expected))
+}
+
+/// Runs `parser` over one `line` borrowed from `input`, so errors say where
+/// in `input` it went wrong
+pub fn parse_line<'a, I, T>(
+    input: &'a str,
+    line: &'a str,
+    parser: impl Parser<I, T, NomError<I>>,
+) -> Result<T, ParseError>
+where
+    I: Input<'a>,
+{
+    run(line, parser).map_err(|e| {
+        let offset = input.offset(line) + e.input.offset_in(line);
+        ParseError::at(input, offset, e.expected)
+    })
+}
+
+/// Runs `parser` over every line of `input`, stopping at the first that
+/// doesn't parse
+pub fn parse_lines<'a, I, T>(
+    input: &'a str,
+    mut parser: impl Parser<I, T, NomError<I>>,
+) -> Result<Vec<T>, ParseError>
+where
+    I: Input<'a>,
+{
+    input
+        .lines()
+        .map(|line| parse_line(input, line, |i| parser.parse(i)))
+        .collect()
+}
+
+/// Runs `item` back to back until `input` runs out. Unlike `many1` a bad
+/// item is reported as it is rather than ending the list early.
+pub fn parse_many<'a, I, T>(
+    input: &'a str,
+    mut item: impl Parser<I, T, NomError<I>>,
+) -> Result<Vec<T>, ParseError>
+where
+    I: Input<'a>,
+{
+    let mut rest = I::from(input);
+    let mut items = Vec::new();
+    while rest.input_len() > 0 {
+        let (next, parsed) = item
+            .parse(rest.clone())
+            .finish()
+            .map_err(|e| ParseError::at(input, e.input.offset_in(input), e.expected))?;
+        if next.input_len() == rest.input_len() {
+            return Err(ParseError::at(
+                input,
+                rest.offset_in(input),
+                "the parser got stuck here",
+            ));
+        }
+        items.push(parsed);
+        rest = next;
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        branch::alt,
+        character::complete::{i64, newline},
+        combinator::map,
+        sequence::{preceded, terminated},
+    };
+
+    use super::*;
+
+    type Span<'a> = LocatedSpan<&'a str>;
+
+    fn instruction(i: &str) -> IResult<&str, Option<i64>> {
+        alt((
+            map(tag("noop"), |_| None),
+            map(preceded(tag("addx "), i64), Some),
+        ))(i)
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "noop\naddx x\nnoop\n";
+        let err = parse_lines(input, instruction).unwrap_err();
+        assert_eq!((2, 6), (err.line, err.column));
+        assert_eq!(
+            "line 2, column 6: expected a number\n 2 | addx x\n   |      ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_alternatives() {
+        let err = parse_lines("noop\nmul 3", instruction).unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert_eq!(r#"expected "noop" or "addx ""#, err.expected);
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let err = parse_all("addx 3 oops", instruction).unwrap_err();
+        assert_eq!(7, err.column);
+        assert_eq!("expected the end of the input", err.expected);
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let item = |i| terminated(instruction, newline)(i);
+        assert_eq!(
+            Ok(vec![None, Some(-2)]),
+            parse_many("noop\naddx -2\n", item)
+        );
+        let err = parse_many("noop\naddx 2 \nnoop\n", item).unwrap_err();
+        assert_eq!((2, 7), (err.line, err.column));
+        assert_eq!("expected a new line", err.expected);
+    }
+
+    #[test]
+    fn test_spans() {
+        fn number(i: Span) -> IResult<Span, i64> {
+            i64(i)
+        }
+        assert_eq!(Ok(vec![1, 2]), parse_lines("1\n2", number));
+        let err = parse_lines("1\n2\n3x\n", number).unwrap_err();
+        assert_eq!(
+            (3, 2, "3x"),
+            (err.line, err.column, err.source_line.as_str())
+        );
+    }
+
+    #[test]
+    fn test_external_errors() {
+        let even = |i| {
+            nom::combinator::map_res(i64, |n| match n % 2 {
+                0 => Ok(n),
+                _ => Err(format!("{n} is odd")),
+            })(i)
+        };
+        let err = parse_lines::<&str, _>("2\n\n 4\n7", even).unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        let err = parse_lines::<&str, _>("2\n4\n7", even).unwrap_err();
+        assert_eq!(
+            "line 3, column 1: 7 is odd\n 3 | 7\n   | ^",
+            err.to_string()
+        );
+    }
+}
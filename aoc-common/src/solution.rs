@@ -0,0 +1,242 @@
+use std::any::Any;
+
+use eyre::eyre;
+
+use crate::{differential::Differential, generate::Generator, tool::Tool, Answer, Param};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn both() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(eyre!("part must be 1 or 2, not {s:?}")),
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// One day of the calendar. `parse` runs once and both parts work off the
+/// parsed model, so anything that mutates state has to clone it first.
+pub trait Solution {
+    const DAY: u8;
+    /// The puzzle input, baked in with `include_str!`
+    const INPUT: &'static str;
+    /// The worked example from the puzzle text, where we've kept one
+    const SAMPLE: Option<&'static str> = None;
+    /// Parameters the worked example needs in place of the defaults, by name
+    const SAMPLE_PARAMS: &'static [(&'static str, &'static str)] = &[];
+
+    type Input: 'static;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(&self, input: &str) -> eyre::Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> eyre::Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> eyre::Result<Self::Part2>;
+
+    /// The knobs this day has, as currently set
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Changes one of the knobs from [`Solution::params`]
+    fn set_param(&mut self, name: &str, _value: &str) -> eyre::Result<()> {
+        Err(eyre!("day {} has no parameter {name:?}", Self::DAY))
+    }
+
+    /// Something to make up more input with, for days that have one
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        None
+    }
+
+    /// Slow but obviously right ways of solving this day, each paired with
+    /// the fast way it checks
+    fn differentials(&self) -> Vec<Differential> {
+        Vec::new()
+    }
+
+    /// What else this day can do with its input, set up with its current
+    /// knobs
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        Vec::new()
+    }
+}
+
+/// Output of [`Puzzle::parse`]; only the puzzle that made it can read it.
+pub struct Parsed(Box<dyn Any>);
+
+/// Type-erased [`Solution`] so the runner can keep every day in one list.
+pub trait Puzzle: Send + Sync {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn sample(&self) -> Option<&'static str>;
+    fn sample_params(&self) -> &'static [(&'static str, &'static str)];
+    fn params(&self) -> Vec<Param>;
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()>;
+    fn generator(&self) -> Option<Box<dyn Generator>>;
+    fn differentials(&self) -> Vec<Differential>;
+    fn tools(&self) -> Vec<Box<dyn Tool>>;
+    fn parse(&self, input: &str) -> eyre::Result<Parsed>;
+    fn part1(&self, parsed: &Parsed) -> eyre::Result<Answer>;
+    fn part2(&self, parsed: &Parsed) -> eyre::Result<Answer>;
+
+    fn part(&self, parsed: &Parsed, part: Part) -> eyre::Result<Answer> {
+        match part {
+            Part::One => self.part1(parsed),
+            Part::Two => self.part2(parsed),
+        }
+    }
+
+    fn solve(&self, input: &str, part: Part) -> eyre::Result<Answer> {
+        let parsed = self.parse(input)?;
+        self.part(&parsed, part)
+    }
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> eyre::Result<&S::Input> {
+    parsed
+        .0
+        .downcast_ref()
+        .ok_or_else(|| eyre!("day {} was handed input parsed by another day", S::DAY))
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Send + Sync,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn sample(&self) -> Option<&'static str> {
+        S::SAMPLE
+    }
+
+    fn sample_params(&self) -> &'static [(&'static str, &'static str)] {
+        S::SAMPLE_PARAMS
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Solution::params(self)
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        Solution::set_param(self, name, value)
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Solution::generator(self)
+    }
+
+    fn differentials(&self) -> Vec<Differential> {
+        Solution::differentials(self)
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        Solution::tools(self)
+    }
+
+    fn parse(&self, input: &str) -> eyre::Result<Parsed> {
+        Ok(Parsed(Box::new(Solution::parse(self, input)?)))
+    }
+
+    fn part1(&self, parsed: &Parsed) -> eyre::Result<Answer> {
+        Solution::part1(self, downcast::<S>(parsed)?).map(Into::into)
+    }
+
+    fn part2(&self, parsed: &Parsed) -> eyre::Result<Answer> {
+        Solution::part2(self, downcast::<S>(parsed)?).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler {
+        factor: i64,
+    }
+
+    impl Solution for Doubler {
+        const DAY: u8 = 99;
+        const INPUT: &'static str = "21";
+
+        type Input = i64;
+        type Part1 = i64;
+        type Part2 = String;
+
+        fn parse(&self, input: &str) -> eyre::Result<i64> {
+            Ok(input.trim().parse()?)
+        }
+
+        fn part1(&self, input: &i64) -> eyre::Result<i64> {
+            Ok(input * self.factor)
+        }
+
+        fn part2(&self, input: &i64) -> eyre::Result<String> {
+            Ok(format!("{input}{input}"))
+        }
+
+        fn params(&self) -> Vec<Param> {
+            vec![Param::new(
+                "factor",
+                "what part 1 multiplies by",
+                self.factor,
+            )]
+        }
+
+        fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+            match name {
+                "factor" => self.factor = crate::parse_param(name, value)?,
+                _ => return Err(eyre!("day 99 has no parameter {name:?}")),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_puzzle_from_solution() -> eyre::Result<()> {
+        let puzzle: Box<dyn Puzzle> = Box::new(Doubler { factor: 2 });
+        assert_eq!(99, puzzle.day());
+        assert_eq!(Answer::Number(42), puzzle.solve(puzzle.input(), Part::One)?);
+        assert_eq!(Answer::from("2121"), puzzle.solve("21", Part::Two)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_params() -> eyre::Result<()> {
+        let mut puzzle: Box<dyn Puzzle> = Box::new(Doubler { factor: 2 });
+        puzzle.set_param("factor", "3")?;
+        assert_eq!("3", puzzle.params()[0].value);
+        assert_eq!(Answer::Number(63), puzzle.solve("21", Part::One)?);
+        assert!(puzzle.set_param("factor", "x").is_err());
+        assert!(puzzle.set_param("divisor", "3").is_err());
+        Ok(())
+    }
+}
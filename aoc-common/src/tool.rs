@@ -0,0 +1,36 @@
+//! Extra things a day can do with its input besides solving it, like
+//! summing up day 1's ledger. Days hand theirs out through
+//! [`Solution::tools`](crate::Solution::tools), and the runner runs them as
+//! `aoc day <day> <tool>`.
+
+use std::io::BufRead;
+
+use eyre::eyre;
+
+use crate::Param;
+
+/// One of a day's tools. The knobs work like a [`Generator`]'s: set by name
+/// from text.
+///
+/// [`Generator`]: crate::generate::Generator
+pub trait Tool {
+    fn name(&self) -> &'static str;
+
+    /// A line saying what it does, for listing a day's tools
+    fn about(&self) -> &'static str;
+
+    /// The knobs, as currently set
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Changes one of the knobs from [`Tool::params`]
+    fn set_param(&mut self, name: &str, _value: &str) -> eyre::Result<()> {
+        Err(eyre!("{} has no parameter {name:?}", self.name()))
+    }
+
+    /// Works through the day's input and hands back what to print. Input
+    /// can come from a file or stdin, so a tool that doesn't need all of it
+    /// at once can read a line at a time.
+    fn run(&self, input: &mut dyn BufRead) -> eyre::Result<String>;
+}
@@ -0,0 +1,52 @@
+/// A metric that adds up over a simulation, like a height or a count, so a
+/// cycle adds the same amount every time round
+pub trait Additive: Clone {
+    /// `self` plus `times` lots of what was gained between `start` and `end`
+    fn advance(&self, start: &Self, end: &Self, times: u64) -> Self;
+}
+
+macro_rules! additive {
+    ($($t:ty),*) => {
+        $(impl Additive for $t {
+            fn advance(&self, start: &Self, end: &Self, times: u64) -> Self {
+                let gained = (*end as i128 - *start as i128) * times as i128;
+                Self::try_from(*self as i128 + gained).expect("metric overflowed")
+            }
+        })*
+    };
+}
+
+additive!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// One metric per thing, like inspections per monkey
+impl<T: Additive> Additive for Vec<T> {
+    fn advance(&self, start: &Self, end: &Self, times: u64) -> Self {
+        self.iter()
+            .zip(start)
+            .zip(end)
+            .map(|((x, s), e)| x.advance(s, e, times))
+            .collect()
+    }
+}
+
+impl<A: Additive, B: Additive> Additive for (A, B) {
+    fn advance(&self, start: &Self, end: &Self, times: u64) -> Self {
+        (
+            self.0.advance(&start.0, &end.0, times),
+            self.1.advance(&start.1, &end.1, times),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance() {
+        assert_eq!(25, 5i64.advance(&2, &6, 5));
+        assert_eq!(3u8, 3u8.advance(&7, &7, 1000));
+        assert_eq!(vec![7, 1], vec![1, 1].advance(&vec![0, 4], &vec![3, 4], 2));
+        assert_eq!((3, -3), (1, -1).advance(&(0, 0), &(1, -1), 2));
+    }
+}
@@ -0,0 +1,5 @@
+mod additive;
+mod timeline;
+
+pub use additive::Additive;
+pub use timeline::{find_cycle, Cycle, Timeline};
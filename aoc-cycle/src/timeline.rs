@@ -0,0 +1,137 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::Additive;
+
+/// The state after `start` steps comes round again every `period` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+/// A metric at every step simulated, and once the simulation has started
+/// repeating, at any step at all
+#[derive(Debug, Clone)]
+pub struct Timeline<M> {
+    /// `history[n]` is the metric after `n` steps
+    history: Vec<M>,
+    cycle: Option<Cycle>,
+}
+
+impl<M: Additive> Timeline<M> {
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// How many steps were actually simulated
+    pub fn steps(&self) -> usize {
+        self.history.len() - 1
+    }
+
+    /// The metric after `step` steps. `None` when that's past what was
+    /// simulated and no cycle turned up.
+    pub fn at(&self, step: usize) -> Option<M> {
+        if let Some(m) = self.history.get(step) {
+            return Some(m.clone());
+        }
+        let Cycle { start, period } = self.cycle?;
+        let (times, offset) = ((step - start) / period, (step - start) % period);
+        let gained = (&self.history[start], &self.history[start + period]);
+        Some(self.history[start + offset].advance(gained.0, gained.1, times as u64))
+    }
+}
+
+/// Steps `state` until its fingerprint comes round again, or `limit` steps
+/// have gone by, noting `metric` at the start and after every step.
+///
+/// The fingerprint has to pin down everything about the state that the
+/// rest of the run depends on, and nothing the metric is counting, or the
+/// cycle won't be real (or won't be found).
+pub fn find_cycle<S, K, M>(
+    state: &mut S,
+    limit: usize,
+    mut step: impl FnMut(&mut S),
+    fingerprint: impl Fn(&S) -> K,
+    metric: impl Fn(&S) -> M,
+) -> Timeline<M>
+where
+    K: Hash + Eq,
+    M: Additive,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![metric(state)];
+    seen.insert(fingerprint(state), 0);
+    for n in 1..=limit {
+        step(state);
+        history.push(metric(state));
+        if let Some(start) = seen.insert(fingerprint(state), n) {
+            let cycle = Cycle {
+                start,
+                period: n - start,
+            };
+            return Timeline {
+                history,
+                cycle: Some(cycle),
+            };
+        }
+    }
+    Timeline {
+        history,
+        cycle: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A counter that goes 0, 1, 2, 3, 4, 5, 3, 4, 5, ... and totals what it's
+    /// been
+    fn run(limit: usize) -> Timeline<u64> {
+        let mut state = (0, 0u64);
+        find_cycle(
+            &mut state,
+            limit,
+            |(n, total)| {
+                *n = if *n == 5 { 3 } else { *n + 1 };
+                *total += *n;
+            },
+            |&(n, _)| n,
+            |&(_, total)| total,
+        )
+    }
+
+    fn brute_force(steps: usize) -> u64 {
+        let mut state = (0, 0);
+        for _ in 0..steps {
+            state.0 = if state.0 == 5 { 3 } else { state.0 + 1 };
+            state.1 += state.0;
+        }
+        state.1
+    }
+
+    #[test]
+    fn test_extrapolates() {
+        let timeline = run(1000);
+        assert_eq!(
+            Some(Cycle {
+                start: 3,
+                period: 3
+            }),
+            timeline.cycle()
+        );
+        assert_eq!(6, timeline.steps());
+        for steps in 0..100 {
+            assert_eq!(Some(brute_force(steps)), timeline.at(steps), "{steps}");
+        }
+        assert_eq!(Some(11_999_999_994), timeline.at(3_000_000_000));
+    }
+
+    #[test]
+    fn test_limit() {
+        let timeline = run(4);
+        assert_eq!(None, timeline.cycle());
+        assert_eq!(Some(brute_force(4)), timeline.at(4));
+        assert_eq!(None, timeline.at(5));
+    }
+}
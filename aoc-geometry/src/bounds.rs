@@ -0,0 +1,75 @@
+use crate::{Coord, Vec2, Vector};
+
+/// The smallest box holding a set of points, edges included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V: Vector> Bounds<V> {
+    /// Just the one point
+    pub fn new(point: V) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// `None` when there aren't any points
+    pub fn from_points(points: impl IntoIterator<Item = V>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for point in points {
+            bounds.extend(point);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the box until `point` is in it
+    pub fn extend(&mut self, point: V) {
+        self.min = self.min.component_min(point);
+        self.max = self.max.component_max(point);
+    }
+
+    pub fn contains(&self, point: V) -> bool {
+        self.min.component_max(point) == point && self.max.component_min(point) == point
+    }
+}
+
+impl<T: Coord> Bounds<Vec2<T>> {
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec3;
+
+    #[test]
+    fn test_bounds() {
+        let points = [Vec2::new(3, -1), Vec2::new(0, 4), Vec2::new(2, 2)];
+        let bounds = Bounds::from_points(points).unwrap();
+        assert_eq!(Vec2::new(0, -1), bounds.min);
+        assert_eq!(Vec2::new(3, 4), bounds.max);
+        assert_eq!((4, 6), (bounds.width(), bounds.height()));
+        assert!(points.iter().all(|&p| bounds.contains(p)));
+        assert!(!bounds.contains(Vec2::new(4, 0)));
+        assert!(!bounds.contains(Vec2::new(1, -2)));
+        assert_eq!(None, Bounds::<Vec2<i32>>::from_points([]));
+    }
+
+    #[test]
+    fn test_bounds_3d() {
+        let mut bounds = Bounds::new(Vec3::new(1, 1, 1));
+        bounds.extend(Vec3::new(3, 0, 2));
+        assert!(bounds.contains(Vec3::new(2, 1, 2)));
+        assert!(!bounds.contains(Vec3::new(2, 1, 3)));
+    }
+}
@@ -0,0 +1,109 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Coord, Vec2};
+
+/// The four ways to move on a map. `y` grows downwards, the way puzzle maps
+/// are drawn, so `Up` is `(0, -1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from the top
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// One step this way
+    pub fn delta<T: Coord>(self) -> Vec2<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Direction::Up => Vec2::new(zero, -one),
+            Direction::Right => Vec2::new(one, zero),
+            Direction::Down => Vec2::new(zero, one),
+            Direction::Left => Vec2::new(-one, zero),
+        }
+    }
+}
+
+impl<T: Coord> From<Direction> for Vec2<T> {
+    fn from(direction: Direction) -> Self {
+        direction.delta()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a direction", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// `U`, `R`, `D` and `L`, or the compass points `N`, `E`, `S` and `W`
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" => Ok(Direction::Up),
+            "R" | "E" => Ok(Direction::Right),
+            "D" | "S" => Ok(Direction::Down),
+            "L" | "W" => Ok(Direction::Left),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Direction::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Right, Up.turn_right());
+        assert_eq!(Up, Left.turn_right());
+        assert_eq!(Left, Up.turn_left());
+        assert_eq!(Up, Right.turn_left());
+        assert_eq!(Down, Up.reverse());
+        for d in Direction::ALL {
+            assert_eq!(d, d.turn_left().turn_right());
+            assert_eq!(d.reverse(), d.turn_right().turn_right());
+            assert_eq!(-d.delta::<i32>(), d.reverse().delta());
+            assert_eq!(d.delta::<i64>(), Vec2::from(d));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Up), "U".parse());
+        assert_eq!(Ok(Left), "W".parse());
+        assert_eq!(
+            r#""x" is not a direction"#,
+            "x".parse::<Direction>().unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod bounds;
+mod direction;
+mod vector;
+
+pub use bounds::Bounds;
+pub use direction::{Direction, ParseDirectionError};
+pub use vector::{Coord, Vec2, Vec3, Vector};
@@ -0,0 +1,264 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// The signed integers a vector can be made of
+pub trait Coord:
+    Copy
+    + Debug
+    + Default
+    + Ord
+    + std::hash::Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        })*
+    };
+}
+
+coord!(i8, i16, i32, i64, i128, isize);
+
+/// What [`crate::Bounds`] needs from a vector
+pub trait Vector: Copy + PartialEq {
+    /// The smaller of each component
+    fn component_min(self, other: Self) -> Self;
+    /// The larger of each component
+    fn component_max(self, other: Self) -> Self;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Arithmetic is the same component by component in any number of
+/// dimensions
+macro_rules! vector {
+    ($name:ident { $($c:ident),* }) => {
+        impl<T: Coord> $name<T> {
+            pub fn new($($c: T),*) -> Self {
+                Self { $($c),* }
+            }
+
+            pub fn zero() -> Self {
+                Self { $($c: T::ZERO),* }
+            }
+
+            pub fn abs(self) -> Self {
+                Self { $($c: self.$c.abs()),* }
+            }
+
+            /// -1, 0 or 1 in each component: one step towards this vector
+            pub fn signum(self) -> Self {
+                Self { $($c: self.$c.signum()),* }
+            }
+
+            /// Taxicab distance, the sum of the distances along each axis
+            pub fn manhattan(self, other: Self) -> T {
+                let d = (self - other).abs();
+                T::ZERO $(+ d.$c)*
+            }
+
+            /// King's move distance, the largest distance along any axis
+            pub fn chebyshev(self, other: Self) -> T {
+                let d = (self - other).abs();
+                T::ZERO $(.max(d.$c))*
+            }
+        }
+
+        impl<T: Coord> Vector for $name<T> {
+            fn component_min(self, other: Self) -> Self {
+                Self { $($c: self.$c.min(other.$c)),* }
+            }
+
+            fn component_max(self, other: Self) -> Self {
+                Self { $($c: self.$c.max(other.$c)),* }
+            }
+        }
+
+        impl<T: Coord> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($c: self.$c + other.$c),* }
+            }
+        }
+
+        impl<T: Coord> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($c: self.$c - other.$c),* }
+            }
+        }
+
+        impl<T: Coord> Neg for $name<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),* }
+            }
+        }
+
+        /// Scaling
+        impl<T: Coord> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, k: T) -> Self {
+                Self { $($c: self.$c * k),* }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Display> Display for $name<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let parts = [$(self.$c.to_string()),*];
+                write!(f, "({})", parts.join(","))
+            }
+        }
+    };
+}
+
+vector!(Vec2 { x, y });
+vector!(Vec3 { x, y, z });
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T: Coord> Vec2<T> {
+    /// The four points sharing an edge with this one
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        crate::Direction::ALL
+            .into_iter()
+            .map(move |d| self + d.delta())
+    }
+
+    /// The eight points around this one, diagonals included
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        let one = T::ONE;
+        [-one, T::ZERO, one]
+            .into_iter()
+            .flat_map(move |dy| [-one, T::ZERO, one].map(|dx| Vec2::new(dx, dy)))
+            .filter(|&d| d != Vec2::zero())
+            .map(move |d| self + d)
+    }
+}
+
+impl<T: Coord> Vec3<T> {
+    /// The six points sharing a face with this one
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        [
+            Vec3::new(one, zero, zero),
+            Vec3::new(-one, zero, zero),
+            Vec3::new(zero, one, zero),
+            Vec3::new(zero, -one, zero),
+            Vec3::new(zero, zero, one),
+            Vec3::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let mut a = Vec2::new(3, -2);
+        let b = Vec2::new(1, 5);
+        assert_eq!(Vec2::new(4, 3), a + b);
+        assert_eq!(Vec2::new(2, -7), a - b);
+        assert_eq!(Vec2::new(-3, 2), -a);
+        assert_eq!(Vec2::new(9, -6), a * 3);
+        a += b;
+        a -= Vec2::new(0, 1);
+        assert_eq!(Vec2::new(4, 2), a);
+        assert_eq!(
+            Vec3::new(2, 4, 6),
+            Vec3::new(1, 2, 3) + Vec3::from((1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Vec2::new(8i64, 7);
+        let b = Vec2::new(2, 10);
+        assert_eq!(9, a.manhattan(b));
+        assert_eq!(6, a.chebyshev(b));
+        assert_eq!(Vec2::new(-1, 1), (b - a).signum());
+        assert_eq!(6, Vec3::new(1, 1, 1).manhattan(Vec3::new(2, 3, 4)));
+        assert_eq!(3, Vec3::new(1, 1, 1).chebyshev(Vec3::new(2, 3, 4)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Vec2::new(0, 0);
+        assert_eq!(4, p.neighbours4().count());
+        assert!(p.neighbours4().all(|n| n.manhattan(p) == 1));
+        assert_eq!(8, p.neighbours8().count());
+        assert!(p.neighbours8().all(|n| n.chebyshev(p) == 1));
+        let cube = Vec3::new(1, 1, 1);
+        assert!(cube.neighbours6().all(|n| n.manhattan(cube) == 1));
+        assert_eq!(6, cube.neighbours6().count());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("(1,-2)", Vec2::new(1, -2).to_string());
+        assert_eq!("(1,2,3)", Vec3::new(1, 2, 3).to_string());
+    }
+}
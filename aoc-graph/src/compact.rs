@@ -0,0 +1,160 @@
+use std::{collections::HashMap, hash::Hash};
+
+use pathfinding::prelude::dijkstra_all;
+
+use crate::NodeSet;
+
+/// A big graph boiled down to the few nodes a search cares about, with the
+/// shortest distance between every pair of them worked out up front. Nodes
+/// are numbered in the order they were given, so searches can work in
+/// indexes and [`NodeSet`]s and only look the nodes up at the end.
+#[derive(Debug, Clone)]
+pub struct CompactGraph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    /// `distances[a][b]`, `None` when there's no way from `a` to `b`
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl<N: Clone + Eq + Hash> CompactGraph<N> {
+    /// Runs Dijkstra from every one of `interesting` over the full graph,
+    /// which `successors` gives as neighbours and the cost of getting there.
+    /// Repeats are dropped. Panics with more nodes than fit in a [`NodeSet`].
+    pub fn new<I>(
+        interesting: impl IntoIterator<Item = N>,
+        mut successors: impl FnMut(&N) -> I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (N, usize)>,
+    {
+        let mut nodes = Vec::new();
+        let mut index = HashMap::new();
+        for node in interesting {
+            index.entry(node.clone()).or_insert_with(|| {
+                nodes.push(node);
+                nodes.len() - 1
+            });
+        }
+        assert!(
+            nodes.len() <= NodeSet::MAX,
+            "{} interesting nodes won't fit in a NodeSet",
+            nodes.len()
+        );
+        let distances = nodes
+            .iter()
+            .map(|from| {
+                let reached = dijkstra_all(from, &mut successors);
+                nodes
+                    .iter()
+                    .map(|to| match to == from {
+                        true => Some(0),
+                        false => reached.get(to).map(|&(_, cost)| cost),
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            nodes,
+            index,
+            distances,
+        }
+    }
+
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+}
+
+impl<N> CompactGraph<N> {
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The node behind index `i`
+    pub fn node(&self, i: usize) -> &N {
+        &self.nodes[i]
+    }
+
+    /// Every node, as a set of indexes
+    pub fn all(&self) -> NodeSet {
+        NodeSet::all(self.len())
+    }
+
+    /// The shortest distance from `a` to `b` in the full graph
+    pub fn distance(&self, a: usize, b: usize) -> Option<usize> {
+        self.distances[a][b]
+    }
+
+    /// The other nodes reachable from `a` that are in `among`, and how far
+    /// away each one is
+    pub fn reachable(&self, a: usize, among: NodeSet) -> impl Iterator<Item = (usize, usize)> + '_ {
+        among
+            .iter()
+            .filter(move |&b| b != a)
+            .filter_map(move |b| Some((b, self.distance(a, b)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ```text
+    /// a -1- x -1- b
+    /// |           |
+    /// 5     c -2- y
+    /// |
+    /// z           d
+    /// ```
+    /// with the lone `d` cut off from everything else
+    fn edges(node: &char) -> Vec<(char, usize)> {
+        let all = [
+            ('a', 'x', 1),
+            ('x', 'b', 1),
+            ('b', 'y', 1),
+            ('y', 'c', 2),
+            ('a', 'z', 5),
+        ];
+        all.iter()
+            .filter_map(|&(p, q, cost)| match *node {
+                n if n == p => Some((q, cost)),
+                n if n == q => Some((p, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_distances() {
+        let graph = CompactGraph::new("abcda".chars(), edges);
+        assert_eq!(4, graph.len());
+        assert_eq!(Some(2), graph.index_of(&'c'));
+        assert_eq!(None, graph.index_of(&'x'));
+        assert_eq!('d', *graph.node(3));
+
+        let (a, b, c, d) = (0, 1, 2, 3);
+        assert_eq!(Some(0), graph.distance(a, a));
+        assert_eq!(Some(2), graph.distance(a, b));
+        assert_eq!(Some(5), graph.distance(c, a));
+        assert_eq!(Some(3), graph.distance(b, c));
+        assert_eq!(None, graph.distance(a, d));
+        assert_eq!(None, graph.distance(d, c));
+        assert_eq!(Some(0), graph.distance(d, d));
+    }
+
+    #[test]
+    fn test_reachable() {
+        let graph = CompactGraph::new("abcd".chars(), edges);
+        let from_a: Vec<_> = graph.reachable(0, graph.all()).collect();
+        assert_eq!(vec![(1, 2), (2, 5)], from_a);
+        let c_and_d = NodeSet::new().with(2).with(3);
+        assert_eq!(
+            vec![(2, 3)],
+            graph.reachable(1, c_and_d).collect::<Vec<_>>()
+        );
+    }
+}
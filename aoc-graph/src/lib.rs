@@ -0,0 +1,5 @@
+mod compact;
+mod node_set;
+
+pub use compact::CompactGraph;
+pub use node_set::NodeSet;
@@ -0,0 +1,191 @@
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr, Not, Sub},
+};
+
+/// A set of node indexes below 64, one bit each, so it's cheap to copy,
+/// compare and use as a key when searching over which nodes were visited
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeSet(u64);
+
+impl NodeSet {
+    pub const MAX: usize = 64;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Nodes `0..n`
+    pub fn all(n: usize) -> Self {
+        assert!(
+            n <= Self::MAX,
+            "a NodeSet holds at most {} nodes",
+            Self::MAX
+        );
+        match n {
+            Self::MAX => Self(u64::MAX),
+            n => Self((1 << n) - 1),
+        }
+    }
+
+    pub fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    fn bit(node: usize) -> u64 {
+        assert!(node < Self::MAX, "node {node} doesn't fit in a NodeSet");
+        1 << node
+    }
+
+    pub fn contains(self, node: usize) -> bool {
+        node < Self::MAX && self.0 & Self::bit(node) != 0
+    }
+
+    /// The set with `node` added, leaving this one as it was
+    pub fn with(self, node: usize) -> Self {
+        Self(self.0 | Self::bit(node))
+    }
+
+    pub fn without(self, node: usize) -> Self {
+        Self(self.0 & !Self::bit(node))
+    }
+
+    pub fn insert(&mut self, node: usize) {
+        *self = self.with(node);
+    }
+
+    pub fn remove(&mut self, node: usize) {
+        *self = self.without(node);
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// The nodes, lowest first
+    pub fn iter(self) -> impl Iterator<Item = usize> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let node = bits.trailing_zeros() as usize;
+            bits &= bits.checked_sub(1)?;
+            Some(node)
+        })
+    }
+
+    /// Every subset of this set, the empty one included
+    pub fn subsets(self) -> impl Iterator<Item = Self> {
+        let mut next = Some(0u64);
+        std::iter::from_fn(move || {
+            let subset = next?;
+            next = (subset != self.0).then(|| (subset.wrapping_sub(self.0)) & self.0);
+            Some(Self(subset))
+        })
+    }
+}
+
+impl BitOr for NodeSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for NodeSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Sub for NodeSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+/// Everything from 0 to 63 that isn't in the set. Intersect with
+/// [`NodeSet::all`] to stay inside a graph.
+impl Not for NodeSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+impl FromIterator<usize> for NodeSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(nodes: I) -> Self {
+        nodes.into_iter().fold(Self::new(), Self::with)
+    }
+}
+
+impl Debug for NodeSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let a: NodeSet = [0, 3, 5].into_iter().collect();
+        let b = NodeSet::new().with(3).with(63);
+        assert_eq!(vec![0, 3, 5], a.iter().collect::<Vec<_>>());
+        assert_eq!("{3, 63}", format!("{b:?}"));
+        assert!(a.contains(5) && !a.contains(4) && !a.contains(64));
+        assert_eq!(4, (a | b).len());
+        assert_eq!(vec![3], (a & b).iter().collect::<Vec<_>>());
+        assert_eq!(vec![0, 5], (a - b).iter().collect::<Vec<_>>());
+        assert_eq!(
+            vec![1, 2, 4],
+            (!a & NodeSet::all(6)).iter().collect::<Vec<_>>()
+        );
+        assert!((a - b).is_disjoint(b));
+        assert!((a & b).is_subset(a));
+        assert_eq!(64, NodeSet::all(64).len());
+        assert!(NodeSet::all(0).is_empty());
+
+        let mut c = a;
+        c.remove(0);
+        c.insert(1);
+        assert_eq!(NodeSet::from_bits(0b101010), c);
+    }
+
+    #[test]
+    fn test_subsets() {
+        let set: NodeSet = [1, 4, 6].into_iter().collect();
+        let subsets: Vec<_> = set.subsets().collect();
+        assert_eq!(8, subsets.len());
+        assert_eq!(NodeSet::new(), subsets[0]);
+        assert_eq!(Some(&set), subsets.last());
+        assert!(subsets.iter().all(|s| s.is_subset(set)));
+        assert_eq!(
+            vec![NodeSet::new()],
+            NodeSet::new().subsets().collect::<Vec<_>>()
+        );
+        assert_eq!(1 << 10, NodeSet::all(10).subsets().count());
+    }
+}
@@ -0,0 +1,295 @@
+mod parse;
+pub mod pos;
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use aoc_geometry::Direction;
+pub use parse::{FromChar, ParseError};
+pub use pos::{Pos, Step};
+
+/// A rectangle of cells addressed by [`Pos`], stored row by row
+#[derive(Debug, Clone)]
+pub struct Grid<T>(grid::Grid<T>);
+
+impl<T: Eq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq> Eq for Grid<T> {}
+
+impl<T: Default> Grid<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Grid(grid::Grid::new(rows, cols))
+    }
+}
+
+impl<T: FromChar> Grid<T> {
+    /// One row per line, one cell per character
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, T::from_char)
+    }
+}
+
+impl<T> Grid<T> {
+    /// For when a day wants something other than its cell type's
+    /// [`FromChar`], like digits as numbers
+    pub fn parse_with(input: &str, f: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut cols = None;
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                cells.push(f(c).ok_or(ParseError::BadCell { row, col, c })?);
+            }
+            let found = cells.len() - before;
+            match cols {
+                None => cols = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseError::Ragged {
+                        row,
+                        expected,
+                        found,
+                    })
+                }
+                _ => {}
+            }
+        }
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self::from_vec(cells, cols)),
+            _ => Err(ParseError::Empty),
+        }
+    }
+
+    /// Panics unless `cells` fills whole rows
+    pub fn from_vec(cells: Vec<T>, cols: usize) -> Self {
+        Grid(grid::Grid::from_vec(cells, cols))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.0.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.0.cols()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.rows() && pos.col < self.cols()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.0.get(pos.row, pos.col)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.0.get_mut(pos.row, pos.col)
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols();
+        (0..self.rows()).flat_map(move |row| (0..cols).map(move |col| Pos { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.0.iter())
+    }
+
+    /// The first matching cell, row by row
+    pub fn find(&self, cell: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.find_all(cell).next()
+    }
+
+    pub fn find_all<'a>(&'a self, cell: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, c)| *c == cell)
+            .map(|(pos, _)| pos)
+    }
+
+    /// One step from `pos`, a [`Direction`] or any other [`Step`], as long
+    /// as it stays on the grid
+    pub fn step(&self, pos: Pos, step: impl Into<Step>) -> Option<Pos> {
+        pos.step(step).filter(|&p| self.contains(p))
+    }
+
+    /// Up, right, down and left of `pos`, leaving out the ones off the edge
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Like [`Grid::neighbours4`] but with the diagonals as well
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos::EIGHT_WAYS
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.0.iter_row(row)
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.0.iter_col(col)
+    }
+
+    /// Every cell from `pos` out to the edge in the direction of `step`,
+    /// not counting `pos` itself
+    pub fn ray(&self, pos: Pos, step: impl Into<Step>) -> impl Iterator<Item = (Pos, &T)> {
+        let step = step.into();
+        std::iter::successors(self.step(pos, step), move |&p| self.step(p, step))
+            .map(|p| (p, &self[p]))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is off the grid"))
+    }
+}
+
+/// The map the way the puzzle draws it
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.rows() {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_geometry::Vec2;
+    use Direction::{Down, Left, Right, Up};
+
+    use super::*;
+
+    const MAP: &str = "ab#
+d.f
+#hi
+";
+
+    fn map() -> Grid<char> {
+        Grid::parse(MAP).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = map();
+        assert_eq!((3, 3), (grid.rows(), grid.cols()));
+        assert_eq!('f', grid[Pos::new(1, 2)]);
+        assert_eq!(MAP, grid.to_string());
+
+        let walls = Grid::<bool>::parse(".#\n#.\n").unwrap();
+        assert!(walls[Pos::new(1, 0)]);
+        assert_eq!(
+            Err(ParseError::BadCell {
+                row: 1,
+                col: 0,
+                c: 'x'
+            }),
+            Grid::<bool>::parse(".#\nx.\n")
+        );
+        assert_eq!(
+            Err(ParseError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 1
+            }),
+            Grid::<bool>::parse(".#\n#\n")
+        );
+        assert_eq!(Err(ParseError::Empty), Grid::<bool>::parse(""));
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let digits = Grid::parse_with("12\n34\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(4, digits[Pos::new(1, 1)]);
+        assert!(Grid::parse_with("1a\n", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_error_message() {
+        let err = ParseError::BadCell {
+            row: 1,
+            col: 0,
+            c: 'x',
+        };
+        assert_eq!("line 2, column 1: 'x' can't be on the map", err.to_string());
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = map();
+        assert_eq!(Some(Pos::new(0, 2)), grid.find(&'#'));
+        assert_eq!(
+            vec![Pos::new(0, 2), Pos::new(2, 0)],
+            grid.find_all(&'#').collect::<Vec<_>>()
+        );
+        assert_eq!(None, grid.find(&'z'));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = map();
+        let corner: Vec<_> = grid.neighbours4(Pos::new(0, 0)).collect();
+        assert_eq!(vec![Pos::new(0, 1), Pos::new(1, 0)], corner);
+        assert_eq!(4, grid.neighbours4(Pos::new(1, 1)).count());
+        assert_eq!(3, grid.neighbours8(Pos::new(2, 2)).count());
+        assert_eq!(8, grid.neighbours8(Pos::new(1, 1)).count());
+    }
+
+    #[test]
+    fn test_rows_cols_rays() {
+        let grid = map();
+        assert_eq!("d.f", grid.row(1).collect::<String>());
+        assert_eq!("b.h", grid.col(1).collect::<String>());
+        assert_eq!("fd", grid.row(1).rev().step_by(2).collect::<String>());
+
+        let cells =
+            |step: Step| -> String { grid.ray(Pos::new(1, 1), step).map(|(_, c)| c).collect() };
+        assert_eq!("b", cells(Up.into()));
+        assert_eq!("h", cells(Down.into()));
+        assert_eq!("d", cells(Left.into()));
+        assert_eq!("f", cells(Right.into()));
+        assert_eq!("i", cells(Vec2::new(1, 1)));
+        let ray: Vec<_> = grid.ray(Pos::new(0, 0), Right).map(|(p, _)| p).collect();
+        assert_eq!(vec![Pos::new(0, 1), Pos::new(0, 2)], ray);
+        assert_eq!(0, grid.ray(Pos::new(0, 0), Up).count());
+    }
+
+    #[test]
+    fn test_new_and_mutate() {
+        let mut grid: Grid<u8> = Grid::new(2, 3);
+        grid[Pos::new(1, 2)] = 7;
+        *grid.get_mut(Pos::new(0, 0)).unwrap() = 1;
+        assert_eq!("100\n007\n", grid.to_string());
+        assert!(grid.get(Pos::new(2, 0)).is_none());
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+/// How one character of a puzzle map becomes a cell
+pub trait FromChar: Sized {
+    /// `None` when the character can't be on the map
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// `#` is set and `.` is clear, which is what most maps use
+impl FromChar for bool {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    /// Rows and columns count from 0
+    BadCell {
+        row: usize,
+        col: usize,
+        c: char,
+    },
+    /// Every row has to be as wide as the first
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "the map is empty"),
+            ParseError::BadCell { row, col, c } => write!(
+                f,
+                "line {}, column {}: {c:?} can't be on the map",
+                row + 1,
+                col + 1
+            ),
+            ParseError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {expected} cells, found {found}",
+                row + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
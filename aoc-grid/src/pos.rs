@@ -0,0 +1,63 @@
+use aoc_geometry::Vec2;
+
+/// A column and row delta, as `x` and `y`. Rows count down the map, the way
+/// [`Direction`](aoc_geometry::Direction) has it, so `Direction::Up` is a
+/// step to the row above.
+pub type Step = Vec2<isize>;
+
+/// The eight ways to step, clockwise from the top, diagonals included
+pub const EIGHT_WAYS: [Step; 8] = [
+    Vec2 { x: 0, y: -1 },
+    Vec2 { x: 1, y: -1 },
+    Vec2 { x: 1, y: 0 },
+    Vec2 { x: 1, y: 1 },
+    Vec2 { x: 0, y: 1 },
+    Vec2 { x: -1, y: 1 },
+    Vec2 { x: -1, y: 0 },
+    Vec2 { x: -1, y: -1 },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// `None` when it would go above the top or past the left edge. The
+    /// other edges depend on the grid.
+    pub fn step(self, step: impl Into<Step>) -> Option<Pos> {
+        let step = step.into();
+        Some(Pos {
+            row: self.row.checked_add_signed(step.y)?,
+            col: self.col.checked_add_signed(step.x)?,
+        })
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos { row, col }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_geometry::Direction;
+
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let pos = Pos::new(0, 3);
+        assert_eq!(Some(Pos::new(1, 3)), pos.step(Direction::Down));
+        assert_eq!(Some(Pos::new(0, 2)), pos.step(Direction::Left));
+        assert_eq!(None, pos.step(Direction::Up));
+        assert_eq!(None, Pos::new(4, 0).step(Vec2::new(-1, 1)));
+        assert_eq!(Some(Pos::new(5, 1)), Pos::new(4, 0).step(EIGHT_WAYS[3]));
+    }
+}
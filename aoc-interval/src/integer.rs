@@ -0,0 +1,45 @@
+use std::fmt::Debug;
+
+/// The integers an interval can be made of. Intervals include both ends, so
+/// all that's needed on top of ordering is the neighbouring values.
+pub trait Integer: Copy + Debug + Ord {
+    fn checked_next(self) -> Option<Self>;
+    fn checked_prev(self) -> Option<Self>;
+
+    /// How many values `start..=end` holds, saturating at `u64::MAX`
+    fn width(start: Self, end: Self) -> u64;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn checked_next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn checked_prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn width(start: Self, end: Self) -> u64 {
+                (end as i128 - start as i128 + 1).clamp(0, u64::MAX as i128) as u64
+            }
+        })*
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width() {
+        assert_eq!(5, i32::width(-2, 2));
+        assert_eq!(1, u8::width(7, 7));
+        assert_eq!(0, u8::width(7, 6));
+        assert_eq!(u64::MAX, u64::width(0, u64::MAX));
+        assert_eq!(None, u8::MAX.checked_next());
+    }
+}
@@ -0,0 +1,5 @@
+mod integer;
+mod set;
+
+pub use integer::Integer;
+pub use set::IntervalSet;
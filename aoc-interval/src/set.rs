@@ -0,0 +1,335 @@
+use std::ops::RangeInclusive;
+
+use crate::Integer;
+
+/// A set of integers kept as sorted, disjoint intervals. Intervals that
+/// touch are merged, so `1..=3` and `4..=6` are stored as `1..=6`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+/// One past `value`, or `value` itself at the top of the type, which is
+/// enough to tell whether two intervals touch
+fn after<T: Integer>(value: T) -> T {
+    value.checked_next().unwrap_or(value)
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut lo, mut hi) = range.into_inner();
+        if lo > hi {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, e)| after(e) < lo);
+        let last = self.ranges.partition_point(|&(s, _)| s <= after(hi));
+        if first < last {
+            lo = lo.min(self.ranges[first].0);
+            hi = hi.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(lo, hi)]);
+    }
+
+    /// Takes out every value in `range`
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (lo, hi) = range.into_inner();
+        if lo > hi {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, e)| e < lo);
+        let last = self.ranges.partition_point(|&(s, _)| s <= hi);
+        if first == last {
+            return;
+        }
+        let mut leftovers = Vec::with_capacity(2);
+        let (s, _) = self.ranges[first];
+        if let Some(before) = lo.checked_prev().filter(|_| s < lo) {
+            leftovers.push((s, before));
+        }
+        let (_, e) = self.ranges[last - 1];
+        if let Some(next) = hi.checked_next().filter(|_| e > hi) {
+            leftovers.push((next, e));
+        }
+        self.ranges.splice(first..last, leftovers);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// Whether every value in `range` is in the set. An empty range always
+    /// is.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        let (lo, hi) = (*range.start(), *range.end());
+        if lo > hi {
+            return true;
+        }
+        let i = self.ranges.partition_point(|&(_, e)| e < lo);
+        self.ranges.get(i).is_some_and(|&(s, e)| s <= lo && hi <= e)
+    }
+
+    /// Whether any value in `range` is in the set
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        let (lo, hi) = (*range.start(), *range.end());
+        if lo > hi {
+            return false;
+        }
+        let i = self.ranges.partition_point(|&(_, e)| e < lo);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= hi)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.extend(other.iter());
+        set
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(s1, e1)), Some(&&(s2, e2))) = (a.peek(), b.peek()) {
+            let (lo, hi) = (s1.max(s2), e1.min(e2));
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+            if e1 < e2 {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        for range in other.iter() {
+            set.remove(range);
+        }
+        set
+    }
+
+    /// Everything in `bounds` that isn't in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        IntervalSet::from(bounds).difference(self)
+    }
+
+    /// How many values are in the set, saturating at `u64::MAX`
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .fold(0u64, |n, &(s, e)| n.saturating_add(T::width(s, e)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The intervals, lowest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The holes between the intervals, lowest first
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).filter_map(|w| {
+            let (lo, hi) = (w[0].1.checked_next()?, w[1].0.checked_prev()?);
+            Some(lo..=hi)
+        })
+    }
+
+    /// From the lowest value to the highest
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        let (lo, _) = self.ranges.first()?;
+        let (_, hi) = self.ranges.last()?;
+        Some(*lo..=*hi)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<RangeInclusive<i32>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let s = set(&[5..=7, 0..=2, 3..=3, 10..=12, 9..=9]);
+        assert_eq!(vec![0..=3, 5..=7, 9..=12], ranges(&s));
+        assert_eq!(11, s.len());
+        let s = set(&[0..=2, 6..=8, 1..=7]);
+        assert_eq!(vec![0..=8], ranges(&s));
+        #[allow(clippy::reversed_empty_ranges)]
+        let s = set(&[3..=1]);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut s = set(&[0..=10, 20..=30]);
+        s.remove(5..=22);
+        assert_eq!(vec![0..=4, 23..=30], ranges(&s));
+        s.remove(0..=0);
+        s.remove(30..=40);
+        assert_eq!(vec![1..=4, 23..=29], ranges(&s));
+    }
+
+    #[test]
+    fn test_queries() {
+        let s = set(&[0..=13, 15..=20]);
+        assert!(s.contains(13));
+        assert!(!s.contains(14));
+        assert!(s.contains_range(&(2..=8)));
+        assert!(!s.contains_range(&(12..=16)));
+        assert!(s.overlaps(&(14..=15)));
+        assert!(!s.overlaps(&(21..=30)));
+        assert_eq!(vec![14..=14], s.gaps().collect::<Vec<_>>());
+        assert_eq!(Some(0..=20), s.span());
+        assert_eq!(vec![-2..=-1, 14..=14], ranges(&s.complement(-2..=20)));
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut s = IntervalSet::from(250u8..=255);
+        s.insert(0..=3);
+        assert_eq!(10, s.len());
+        assert_eq!(
+            vec![4..=249],
+            s.complement(0..=255).iter().collect::<Vec<_>>()
+        );
+        s.remove(0..=255);
+        assert!(s.is_empty());
+        assert_eq!(u64::MAX, IntervalSet::from(0..=u64::MAX).len());
+    }
+
+    /// xorshift, so the property tests can roll dice without a dependency
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+    }
+
+    /// The naive model: bit `v - LOW` is set when `v` is in the set
+    const LOW: i32 = -8;
+    const HIGH: i32 = 55;
+
+    fn bitmap(range: RangeInclusive<i32>) -> u64 {
+        range.fold(0, |bits, v| bits | 1 << (v - LOW))
+    }
+
+    fn random_range(rng: &mut Rng) -> RangeInclusive<i32> {
+        let a = LOW + rng.below(64) as i32;
+        let b = a + rng.below(12) as i32 - 1;
+        a..=b.min(HIGH)
+    }
+
+    fn random_set(rng: &mut Rng) -> (IntervalSet<i32>, u64) {
+        let mut set = IntervalSet::new();
+        let mut bits = 0;
+        for _ in 0..rng.below(8) {
+            let range = random_range(rng);
+            if rng.below(4) == 0 {
+                bits &= !bitmap(range.clone());
+                set.remove(range);
+            } else {
+                bits |= bitmap(range.clone());
+                set.insert(range);
+            }
+        }
+        (set, bits)
+    }
+
+    fn check(set: &IntervalSet<i32>, bits: u64) {
+        // sorted, non-empty and never touching
+        for &(s, e) in &set.ranges {
+            assert!(s <= e, "{set:?}");
+        }
+        for w in set.ranges.windows(2) {
+            assert!(w[0].1 + 1 < w[1].0, "{set:?}");
+        }
+        let from_set = set.iter().fold(0, |b, r| b | bitmap(r));
+        assert_eq!(bits, from_set, "{set:?}");
+        assert_eq!(bits.count_ones() as u64, set.len());
+        for v in LOW..=HIGH {
+            assert_eq!(bits & bitmap(v..=v) != 0, set.contains(v), "{v} in {set:?}");
+        }
+    }
+
+    #[test]
+    fn test_against_bitmap() {
+        let mut rng = Rng(0x2022_1215);
+        for _ in 0..2000 {
+            let (a, a_bits) = random_set(&mut rng);
+            let (b, b_bits) = random_set(&mut rng);
+            check(&a, a_bits);
+            check(&a.union(&b), a_bits | b_bits);
+            check(&a.intersection(&b), a_bits & b_bits);
+            check(&a.difference(&b), a_bits & !b_bits);
+            let bounds = random_range(&mut rng);
+            check(&a.complement(bounds.clone()), !a_bits & bitmap(bounds));
+
+            let range = random_range(&mut rng);
+            let range_bits = bitmap(range.clone());
+            assert_eq!(a_bits & range_bits == range_bits, a.contains_range(&range));
+            assert_eq!(a_bits & range_bits != 0, a.overlaps(&range));
+
+            let gap_bits = a.gaps().fold(0, |b, r| b | bitmap(r));
+            let span_bits = a.span().map_or(0, bitmap);
+            assert_eq!(span_bits & !a_bits, gap_bits, "{a:?}");
+        }
+    }
+}
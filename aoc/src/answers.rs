@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+
+use aoc_common::{
+    parse::{parse_line, tag, IResult},
+    Part,
+};
+use color_eyre::eyre::eyre;
+use nom::{
+    branch::alt,
+    bytes::complete::take_till1,
+    character::complete::{self as cc, not_line_ending, space1},
+    combinator::{map, value},
+    sequence::{preceded, tuple},
+};
+
+/// Where `aoc verify` looks for answers unless told otherwise
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    /// `input`, `sample`, ...
+    pub input: String,
+}
+
+impl Key {
+    pub fn new(day: u8, part: Part, input: &str) -> Self {
+        Self {
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+}
+
+/// The known-good answers, one per line as `day part input answer`, and the
+/// checks known to fail, as `fails day part input why`
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<Key, String>,
+    failing: BTreeMap<Key, String>,
+}
+
+enum Line<'a> {
+    Answer(Key, &'a str),
+    Fails(Key, &'a str),
+}
+
+impl Answers {
+    pub fn load(path: &str) -> color_eyre::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> color_eyre::Result<Self> {
+        let mut answers = Answers::default();
+        for line in contents.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match parse_line(contents, line, entry).map_err(|e| eyre!("answers {e}"))? {
+                Line::Answer(key, answer) => answers.insert(key, unescape(answer)),
+                Line::Fails(key, why) => {
+                    answers.failing.insert(key, why.trim().to_string());
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) {
+        self.answers.insert(key, answer);
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.answers.get(key).map(String::as_str)
+    }
+
+    /// Why `key` is known to fail, if it is
+    pub fn failing(&self, key: &Key) -> Option<&str> {
+        self.failing.get(key).map(String::as_str)
+    }
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape(answer: &str) -> String {
+    let mut s = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                s.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                s.push('\\');
+                chars.next();
+            }
+            _ => s.push(c),
+        }
+    }
+    s
+}
+
+fn part(i: &str) -> IResult<&str, Part> {
+    alt((value(Part::One, tag("1")), value(Part::Two, tag("2"))))(i)
+}
+
+fn key(i: &str) -> IResult<&str, Key> {
+    map(
+        tuple((
+            cc::u8,
+            preceded(space1, part),
+            preceded(space1, map(take_till1(char::is_whitespace), String::from)),
+        )),
+        |(day, part, input)| Key { day, part, input },
+    )(i)
+}
+
+fn entry(i: &str) -> IResult<&str, Line<'_>> {
+    alt((
+        map(
+            tuple((
+                preceded(tuple((tag("fails"), space1)), key),
+                not_line_ending,
+            )),
+            |(key, why)| Line::Fails(key, why),
+        ),
+        map(
+            tuple((key, preceded(space1, not_line_ending))),
+            |(key, answer)| Line::Answer(key, answer),
+        ),
+    ))(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> color_eyre::Result<()> {
+        let answers = Answers::parse("# comment\n\n5 1 sample CMZ\n10 2 input #.\\n.#\\n\n")?;
+        assert_eq!(Some("CMZ"), answers.get(&Key::new(5, Part::One, "sample")));
+        assert_eq!(
+            Some("#.\n.#\n"),
+            answers.get(&Key::new(10, Part::Two, "input"))
+        );
+        assert_eq!(None, answers.get(&Key::new(5, Part::Two, "sample")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_failing() -> color_eyre::Result<()> {
+        let answers = Answers::parse("fails 18 1 example not solved yet\n18 1 input 64\n")?;
+        let key = Key::new(18, Part::One, "example");
+        assert_eq!(Some("not solved yet"), answers.failing(&key));
+        assert_eq!(None, answers.get(&key));
+        assert_eq!(None, answers.failing(&Key::new(18, Part::One, "input")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_line() {
+        let err = Answers::parse("5 1 sample CMZ\n5 3 sample CMZ").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(r#"answers line 2, column 3: expected "1" or "2""#));
+    }
+
+    #[test]
+    fn test_escape_round_trip() {
+        let s = "a\\b\nc\n";
+        assert_eq!(s, unescape(&escape(s)));
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() -> color_eyre::Result<()> {
+        Answers::load(DEFAULT_PATH)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use std::{
+    fmt::Display,
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Part, Puzzle};
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+
+use crate::parallel::panic_message;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+/// Summary of repeated timings, all in nanoseconds so it serializes cleanly
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub stddev_ns: u128,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut ns: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        ns.sort_unstable();
+        let n = ns.len();
+        let median_ns = if n.is_multiple_of(2) {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2
+        } else {
+            ns[n / 2]
+        };
+        let mean = ns.iter().sum::<u128>() as f64 / n as f64;
+        let variance = ns.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n as f64;
+        Some(Self {
+            samples: n,
+            min_ns: ns[0],
+            median_ns,
+            mean_ns: mean.round() as u128,
+            stddev_ns: variance.sqrt().round() as u128,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+fn measure<T>(
+    settings: Settings,
+    mut f: impl FnMut() -> color_eyre::Result<T>,
+) -> color_eyre::Result<Stats> {
+    for _ in 0..settings.warmup {
+        f()?;
+    }
+    let mut times = Vec::with_capacity(settings.samples);
+    for _ in 0..settings.samples {
+        let start = Instant::now();
+        f()?;
+        times.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(&times).expect("at least one sample"))
+}
+
+/// A panic as an error, so one phase that panics doesn't take the rest of
+/// the bench down with it
+fn caught<T>(f: impl FnOnce() -> color_eyre::Result<T>) -> color_eyre::Result<T> {
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|panic| Err(eyre!("panicked: {}", panic_message(panic))))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Times parsing and each part on their own. Parts reuse a single parse so
+/// they only pay for the solving.
+pub fn bench(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    settings: Settings,
+) -> Vec<(Phase, color_eyre::Result<Stats>)> {
+    let mut results = vec![(
+        Phase::Parse,
+        caught(|| measure(settings, || puzzle.parse(input))),
+    )];
+    match caught(|| puzzle.parse(input)) {
+        Ok(parsed) => {
+            for part in Part::both() {
+                results.push((
+                    part.into(),
+                    caught(|| measure(settings, || puzzle.part(&parsed, part))),
+                ));
+            }
+        }
+        Err(e) => {
+            for part in Part::both() {
+                results.push((part.into(), Err(eyre!("parse failed: {e}"))));
+            }
+        }
+    }
+    results
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub entries: Vec<Entry>,
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> color_eyre::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save(&self, path: &str) -> color_eyre::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<&Stats> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.phase == phase)
+            .map(|e| &e.stats)
+    }
+}
+
+/// How the median moved against the baseline, as a fraction (0.1 = 10% slower)
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns.max(1) as f64
+}
+
+/// A slowdown only counts when it's past the threshold and outside the
+/// baseline's own noise
+pub fn is_regression(baseline: &Stats, current: &Stats, threshold: f64) -> bool {
+    change(baseline, current) > threshold
+        && current.median_ns > baseline.median_ns + baseline.stddev_ns
+}
+
+pub fn format_ns(ns: u128) -> String {
+    format!("{:?}", Duration::from_nanos(ns as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+
+    /// Parses the input as a number, and panics on part 2 or a number
+    /// below 0
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 1;
+        const INPUT: &'static str = "7";
+
+        type Input = u32;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(&self, input: &str) -> color_eyre::Result<u32> {
+            Ok(input.parse::<i64>()?.try_into().expect("a positive number"))
+        }
+
+        fn part1(&self, input: &u32) -> color_eyre::Result<u32> {
+            Ok(*input)
+        }
+
+        fn part2(&self, _input: &u32) -> color_eyre::Result<u32> {
+            panic!("boom")
+        }
+    }
+
+    fn stats(median_ns: u128, stddev_ns: u128) -> Stats {
+        Stats {
+            samples: 5,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<_> = [4, 2, 8, 6].into_iter().map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(2, stats.min_ns);
+        assert_eq!(5, stats.median_ns);
+        assert_eq!(5, stats.mean_ns);
+        assert_eq!(2, stats.stddev_ns); // sqrt(5)
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_panics() {
+        let settings = Settings {
+            warmup: 0,
+            samples: 2,
+        };
+        let results = bench(&Fragile, "7", settings);
+        assert!(results[0].1.is_ok());
+        assert!(results[1].1.is_ok());
+        assert_eq!(
+            "panicked: boom",
+            results[2].1.as_ref().unwrap_err().to_string()
+        );
+
+        let results = bench(&Fragile, "-1", settings);
+        assert_eq!(3, results.len());
+        assert!(results[0]
+            .1
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .starts_with("panicked: a positive number"));
+        assert!(results[2]
+            .1
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .starts_with("parse failed: panicked"));
+    }
+
+    #[test]
+    fn test_regression() {
+        let base = stats(1_000, 50);
+        assert!(is_regression(&base, &stats(1_200, 10), 0.1));
+        assert!(!is_regression(&base, &stats(1_050, 10), 0.1));
+        // noisy baselines need a bigger jump
+        assert!(!is_regression(&stats(1_000, 500), &stats(1_200, 10), 0.1));
+        assert!(!is_regression(&base, &stats(500, 10), 0.1));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() -> color_eyre::Result<()> {
+        let baseline = Baseline {
+            entries: vec![Entry {
+                day: 8,
+                phase: Phase::Part1,
+                stats: stats(1_000, 50),
+            }],
+        };
+        let json = serde_json::to_string(&baseline)?;
+        assert!(json.contains(r#""phase":"part1""#));
+        let back: Baseline = serde_json::from_str(&json)?;
+        assert_eq!(Some(&stats(1_000, 50)), back.get(8, Phase::Part1));
+        assert_eq!(None, back.get(8, Phase::Part2));
+        Ok(())
+    }
+}
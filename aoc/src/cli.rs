@@ -0,0 +1,448 @@
+use std::{path::PathBuf, str::FromStr};
+
+use aoc_common::Part;
+use clap::{Args, Parser, Subcommand};
+
+use crate::{params::Override, report::Format};
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2022")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Cache of `dayN/<name>.txt` inputs, used before the built-in ones
+    #[arg(long, global = true, env = "AOC_INPUTS", default_value = crate::inputs::DEFAULT_CACHE_DIR)]
+    pub inputs: PathBuf,
+
+    /// `day.name=value` overrides, one per line
+    #[arg(long, global = true, env = "AOC_PARAMS", default_value = crate::params::DEFAULT_PATH)]
+    pub params: PathBuf,
+
+    /// Set a day's parameter, like `15.row=10`; wins over the params file
+    #[arg(long = "param", global = true)]
+    pub overrides: Vec<Override>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve one day, or every day
+    Run(RunArgs),
+    /// Check every day against the known-good answers
+    Verify(VerifyArgs),
+    /// Time parsing and each part, optionally against a saved baseline
+    Bench(BenchArgs),
+    /// Pull the worked example and its answers out of the saved puzzle pages
+    Extract(ExtractArgs),
+    /// Print the saved puzzle description
+    Show(ShowArgs),
+    /// List the parameters each day takes and their current values
+    Params(ParamsArgs),
+    /// Make up a new input for a day, as big as you like
+    Generate(GenerateArgs),
+    /// Check fast solutions against slow ones on generated inputs
+    Diff(DiffArgs),
+    /// Run one of a day's extra tools, or list them
+    Day(DayArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct DayArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Lists the day's tools when left out
+    pub tool: Option<String>,
+
+    /// Same as for `run`. Files and stdin are handed to the tool as they
+    /// are, so it can read them a line at a time.
+    #[arg(short, long)]
+    pub input: Option<InputSource>,
+
+    /// Set one of the tool's knobs, like `buckets=20`
+    #[arg(long = "set", value_parser = parse_setting, requires = "tool")]
+    pub settings: Vec<(String, String)>,
+
+    /// List the tool's knobs instead
+    #[arg(short, long, requires = "tool")]
+    pub list: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    /// Only check this day; every day with something to check when left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Inputs to try, from seeds 0 up
+    #[arg(short, long, default_value_t = 100)]
+    pub seeds: u64,
+
+    /// Set one of the generator's knobs, on top of what the check picks
+    #[arg(long = "set", value_parser = parse_setting, requires = "day")]
+    pub settings: Vec<(String, String)>,
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// The same seed and settings always make the same input
+    #[arg(short, long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Set one of the generator's knobs, like `sensors=100`
+    #[arg(long = "set", value_parser = parse_setting)]
+    pub settings: Vec<(String, String)>,
+
+    /// Write the input here instead of to stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// List the generator's knobs instead
+    #[arg(short, long)]
+    pub list: bool,
+}
+
+fn parse_setting(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("expected name=value, got {s:?}")),
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ParamsArgs {
+    /// Only this day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, Args)]
+pub struct ShowArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+
+    /// Only this part; every part that was saved when left out
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Wrap paragraphs at this many columns
+    #[arg(short, long, default_value_t = 80)]
+    pub width: usize,
+
+    /// No colors, even on a terminal
+    #[arg(long)]
+    pub plain: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ExtractArgs {
+    /// Only extract this day; every day with a saved page when left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Which `<pre><code>` block is the example, counting from 0
+    #[arg(long, requires = "day")]
+    pub block: Option<usize>,
+
+    /// Which emphasized answer in part one goes with it, counting from 0.
+    /// The last one when left out.
+    #[arg(long, requires = "day")]
+    pub answer: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Only benchmark this day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Same as for `run`
+    #[arg(short, long)]
+    pub input: Option<InputSource>,
+
+    /// Untimed runs before sampling starts
+    #[arg(long, default_value_t = 1)]
+    pub warmup: usize,
+
+    /// Timed runs per phase
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    pub samples: u64,
+
+    /// Compare against a baseline saved earlier with --save
+    #[arg(long)]
+    pub baseline: Option<String>,
+
+    /// Write this run out as a baseline
+    #[arg(long)]
+    pub save: Option<String>,
+
+    /// Percent slowdown of the median that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Only check this day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Answers file to check against
+    #[arg(short, long, default_value = crate::answers::DEFAULT_PATH)]
+    pub answers: String,
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    /// Day to run; every day when left out
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Every day, which is also what happens without a --day
+    #[arg(short, long, conflicts_with = "day")]
+    pub all: bool,
+
+    /// Days to solve at the same time
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    pub jobs: u64,
+
+    /// Seconds each day gets before it's reported as timed out
+    #[arg(short, long)]
+    pub timeout: Option<f64>,
+
+    /// Part to run; both when left out
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// An input name (`input`, `sample`, `example`, ...), `-` for stdin or
+    /// a file path. Defaults to `input`.
+    #[arg(short, long)]
+    pub input: Option<InputSource>,
+
+    /// Print a report instead of the usual text
+    #[arg(long)]
+    pub report: Option<Format>,
+
+    /// Write the report here and still print the text
+    #[arg(short, long, requires = "report")]
+    pub output: Option<PathBuf>,
+}
+
+impl RunArgs {
+    /// Where the report takes over stdout
+    pub fn quiet(&self) -> bool {
+        self.report.is_some() && self.output.is_none()
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::both().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    /// Looked up in the input cache, then in what the day has built in
+    Named(String),
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// How the input shows up in reports
+    pub fn name(source: Option<&InputSource>) -> String {
+        match source {
+            None => "input".to_string(),
+            Some(InputSource::Stdin) => "-".to_string(),
+            Some(InputSource::Named(name)) => name.clone(),
+            Some(InputSource::File(path)) => path.display().to_string(),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_name = s
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            name if is_name => InputSource::Named(name.to_string()),
+            path => InputSource::File(path.into()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_args() {
+        let cli = Cli::parse_from(["aoc", "run", "--day", "14", "--part", "2", "--input", "-"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert_eq!(Some(14), args.day);
+        assert_eq!(vec![Part::Two], args.parts());
+        assert_eq!(Some(InputSource::Stdin), args.input);
+    }
+
+    #[test]
+    fn test_show_args() {
+        let cli = Cli::parse_from(["aoc", "show", "7", "--part", "1"]);
+        let Command::Show(args) = cli.command else {
+            panic!("expected show");
+        };
+        assert_eq!(7, args.day);
+        assert_eq!(Some(Part::One), args.part);
+        assert_eq!(80, args.width);
+    }
+
+    #[test]
+    fn test_all_args() {
+        let cli = Cli::parse_from(["aoc", "run", "--all", "--jobs", "4", "--timeout", "2.5"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert!(args.all);
+        assert_eq!(4, args.jobs);
+        assert_eq!(Some(2.5), args.timeout);
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--day", "3"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--jobs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_report_args() {
+        let cli = Cli::parse_from(["aoc", "run", "--report", "csv"]);
+        let Command::Run(args) = cli.command else {
+            panic!("expected run");
+        };
+        assert_eq!(Some(Format::Csv), args.report);
+        assert!(args.quiet());
+        assert!(Cli::try_parse_from(["aoc", "run", "--output", "out.json"]).is_err());
+    }
+
+    #[test]
+    fn test_param_args() {
+        let cli = Cli::parse_from([
+            "aoc",
+            "run",
+            "--param",
+            "15.row=10",
+            "--param",
+            "11.relief=1",
+        ]);
+        assert_eq!(2, cli.overrides.len());
+        assert_eq!(11, cli.overrides[1].day);
+        assert!(Cli::try_parse_from(["aoc", "run", "--param", "row=10"]).is_err());
+    }
+
+    #[test]
+    fn test_generate_args() {
+        let cli = Cli::parse_from([
+            "aoc",
+            "generate",
+            "15",
+            "--seed",
+            "7",
+            "--set",
+            "sensors=100",
+            "--set",
+            "max = 50",
+        ]);
+        let Command::Generate(args) = cli.command else {
+            panic!("expected generate");
+        };
+        assert_eq!(15, args.day);
+        assert_eq!(7, args.seed);
+        assert_eq!(
+            vec![
+                ("sensors".to_string(), "100".to_string()),
+                ("max".to_string(), "50".to_string())
+            ],
+            args.settings
+        );
+        assert!(Cli::try_parse_from(["aoc", "generate", "1", "--set", "elves"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "generate"]).is_err());
+    }
+
+    #[test]
+    fn test_diff_args() {
+        let cli = Cli::parse_from([
+            "aoc",
+            "diff",
+            "--day",
+            "15",
+            "--seeds",
+            "20",
+            "--set",
+            "sensors=3",
+        ]);
+        let Command::Diff(args) = cli.command else {
+            panic!("expected diff");
+        };
+        assert_eq!(Some(15), args.day);
+        assert_eq!(20, args.seeds);
+        assert_eq!(
+            vec![("sensors".to_string(), "3".to_string())],
+            args.settings
+        );
+        let Command::Diff(args) = Cli::parse_from(["aoc", "diff"]).command else {
+            panic!("expected diff");
+        };
+        assert_eq!(100, args.seeds);
+        assert!(Cli::try_parse_from(["aoc", "diff", "--set", "max=10"]).is_err());
+    }
+
+    #[test]
+    fn test_day_args() {
+        let cli = Cli::parse_from(["aoc", "day", "1", "stats", "-i", "-", "--set", "json=true"]);
+        let Command::Day(args) = cli.command else {
+            panic!("expected day");
+        };
+        assert_eq!((1, Some("stats")), (args.day, args.tool.as_deref()));
+        assert_eq!(Some(InputSource::Stdin), args.input);
+        assert_eq!(
+            vec![("json".to_string(), "true".to_string())],
+            args.settings
+        );
+
+        let cli = Cli::parse_from(["aoc", "day", "2"]);
+        let Command::Day(args) = cli.command else {
+            panic!("expected day");
+        };
+        assert_eq!(None, args.tool);
+        assert!(Cli::try_parse_from(["aoc", "day", "2", "--list"]).is_err());
+    }
+
+    #[test]
+    fn test_inputs_dir() {
+        let cli = Cli::parse_from(["aoc", "run", "--inputs", "/tmp/aoc"]);
+        assert_eq!(PathBuf::from("/tmp/aoc"), cli.inputs);
+    }
+
+    #[test]
+    fn test_day_out_of_range() {
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "26"]).is_err());
+    }
+
+    #[test]
+    fn test_input_sources() {
+        assert_eq!(Ok(InputSource::Named("sample".into())), "sample".parse());
+        assert_eq!(Ok(InputSource::Named("alt_2".into())), "alt_2".parse());
+        assert_eq!(
+            Ok(InputSource::File("day1/input.txt".into())),
+            "day1/input.txt".parse()
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use std::path::PathBuf;
+
+use aoc_common::Part;
+use color_eyre::eyre::{eyre, WrapErr};
+
+use crate::answers::{escape, unescape};
+
+/// The day directories live next to the aoc crate
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The saved puzzle page for a day, if one was saved
+pub fn page_path(day: u8) -> PathBuf {
+    PathBuf::from(format!(
+        "{ROOT}/day{day}/Day {day} - Advent of Code 2022.html"
+    ))
+}
+
+pub fn fixture_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("{ROOT}/day{day}/examples"))
+}
+
+/// One `<article class="day-desc">` per part that has been unlocked
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Article {
+    /// Every `<pre><code>` block, as plain text
+    pub blocks: Vec<String>,
+    /// Every `<code><em>` in the text, which is how the answers get called out
+    pub answers: Vec<String>,
+}
+
+/// Which block is the example input and which emphasized code is its answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Choice {
+    pub block: usize,
+    /// Counted from the start; the last one when left out
+    pub answer: Option<usize>,
+}
+
+impl Choice {
+    /// Most pages give the example first and its answer last. These don't.
+    pub fn for_day(day: u8) -> Self {
+        match day {
+            // several examples, the first answer goes with the first block
+            6 => Choice {
+                block: 0,
+                answer: Some(0),
+            },
+            // day 7 opens with a joke terminal session, day 17 by drawing the rocks
+            7 | 17 => Choice {
+                block: 1,
+                answer: None,
+            },
+            _ => Choice {
+                block: 0,
+                answer: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Every piece of `s` between `open` and the next `close`
+pub fn between<'a>(s: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = s;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        found.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    found
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut text = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+pub fn unescape_html(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+pub fn articles(html: &str) -> Vec<Article> {
+    between(html, r#"<article class="day-desc">"#, "</article>")
+        .into_iter()
+        .map(|article| Article {
+            blocks: between(article, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .map(|block| unescape_html(&strip_tags(block)))
+                .collect(),
+            answers: between(article, "<code><em>", "</em></code>")
+                .into_iter()
+                .map(|answer| unescape_html(&strip_tags(answer)))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Part two reuses the example from part one; it rarely has one of its own
+pub fn extract(html: &str, choice: Choice) -> color_eyre::Result<Example> {
+    let articles = articles(html);
+    let first = articles.first().ok_or_else(|| eyre!("no puzzle text"))?;
+    let input = first
+        .blocks
+        .get(choice.block)
+        .ok_or_else(|| eyre!("no example block {}", choice.block))?
+        .clone();
+    let mut answers = Vec::new();
+    for (part, article) in Part::both().into_iter().zip(&articles) {
+        let answer = match (part, choice.answer) {
+            (Part::One, Some(i)) => article.answers.get(i),
+            _ => article.answers.last(),
+        };
+        let answer = answer.ok_or_else(|| eyre!("no answer for part {part}"))?;
+        answers.push((part, answer.clone()));
+    }
+    Ok(Example { input, answers })
+}
+
+impl Example {
+    /// `None` when the day has no fixture yet
+    pub fn load(day: u8) -> color_eyre::Result<Option<Self>> {
+        let dir = fixture_dir(day);
+        if !dir.exists() {
+            return Ok(None);
+        }
+        let input = std::fs::read_to_string(dir.join("example.txt"))
+            .wrap_err_with(|| format!("reading day {day} example"))?;
+        let answers = std::fs::read_to_string(dir.join("answers.txt"))
+            .wrap_err_with(|| format!("reading day {day} example answers"))?;
+        Ok(Some(Example {
+            input,
+            answers: Self::parse_answers(&answers)?,
+        }))
+    }
+
+    /// One `part answer` per line
+    fn parse_answers(contents: &str) -> color_eyre::Result<Vec<(Part, String)>> {
+        contents
+            .lines()
+            .map(|line| {
+                let (part, answer) = line
+                    .split_once(' ')
+                    .ok_or_else(|| eyre!("bad example answer {line:?}"))?;
+                Ok((part.parse()?, unescape(answer)))
+            })
+            .collect()
+    }
+
+    pub fn save(&self, day: u8) -> color_eyre::Result<()> {
+        let dir = fixture_dir(day);
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("example.txt"), &self.input)?;
+        let answers: String = self
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("{part} {}\n", escape(answer)))
+            .collect();
+        std::fs::write(dir.join("answers.txt"), answers)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 0 ---</h2>
+<p>For example:</p>
+<pre><code>1 -&gt; 2
+<em>3</em> &amp; 4
+</code></pre>
+<p>Not this: <code>12</code>, but <code><em>34</em></code> then <code><em>7</em></code>.</p>
+</article>
+<article class="day-desc"><p>Now it's <code><em>&lt;9&gt;</em></code></p></article>
+</main>"#;
+
+    #[test]
+    fn test_extract() -> color_eyre::Result<()> {
+        let example = extract(
+            PAGE,
+            Choice {
+                block: 0,
+                answer: None,
+            },
+        )?;
+        assert_eq!("1 -> 2\n3 & 4\n", example.input);
+        assert_eq!(
+            vec![(Part::One, "7".to_string()), (Part::Two, "<9>".to_string())],
+            example.answers
+        );
+        let example = extract(
+            PAGE,
+            Choice {
+                block: 0,
+                answer: Some(0),
+            },
+        )?;
+        assert_eq!((Part::One, "34".to_string()), example.answers[0]);
+        assert!(extract(
+            PAGE,
+            Choice {
+                block: 1,
+                answer: None
+            }
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_answers_round_trip() -> color_eyre::Result<()> {
+        let answers = vec![(Part::One, "a\nb".to_string()), (Part::Two, "7".into())];
+        let text: String = answers
+            .iter()
+            .map(|(part, answer)| format!("{part} {}\n", escape(answer)))
+            .collect();
+        assert_eq!(answers, Example::parse_answers(&text)?);
+        Ok(())
+    }
+
+    /// Days that have a fixture but can't solve it yet
+    const KNOWN_FAILING: &[u8] = &[18, 19, 20, 21, 22, 23, 24, 25];
+
+    #[test]
+    fn test_fixtures_match_pages() -> color_eyre::Result<()> {
+        for day in 1..=25 {
+            let Ok(html) = std::fs::read_to_string(page_path(day)) else {
+                continue;
+            };
+            let saved = Example::load(day)?;
+            assert_eq!(
+                Some(extract(&html, Choice::for_day(day))?),
+                saved,
+                "day {day}, rerun `aoc extract`"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixtures() -> color_eyre::Result<()> {
+        for mut puzzle in registry::all() {
+            let day = puzzle.day();
+            crate::params::configure(puzzle.as_mut(), "example", &[])?;
+            let Some(example) = Example::load(day)? else {
+                continue;
+            };
+            if KNOWN_FAILING.contains(&day) {
+                continue;
+            }
+            for (part, expected) in example.answers {
+                let actual = puzzle.solve(&example.input, part)?.to_string();
+                assert_eq!(expected, actual, "day {day} part {part}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Puzzle;
+
+use crate::{cli::InputSource, examples};
+
+/// Holds `dayN/<name>.txt` files; anything here wins over what's built in
+pub const DEFAULT_CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs");
+
+#[derive(Debug)]
+pub enum InputError {
+    /// Not in the cache and not built into the day either
+    Missing {
+        day: u8,
+        name: String,
+        searched: PathBuf,
+    },
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Stdin(std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing {
+                day,
+                name,
+                searched,
+            } => write!(
+                f,
+                "day {day} has no {name} input (looked in {})",
+                searched.display()
+            ),
+            InputError::Read { path, .. } => write!(f, "reading {}", path.display()),
+            InputError::Stdin(_) => write!(f, "reading stdin"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Read { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Resolves a day and an input name (`input`, `sample`, `example`, ...) to
+/// its contents
+#[derive(Debug, Clone)]
+pub struct InputProvider {
+    cache_dir: PathBuf,
+}
+
+impl InputProvider {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    pub fn path(&self, day: u8, name: &str) -> PathBuf {
+        self.cache_dir.join(format!("day{day}/{name}.txt"))
+    }
+
+    fn embedded(puzzle: &dyn Puzzle, name: &str) -> Result<Option<Cow<'static, str>>, InputError> {
+        Ok(match name {
+            "input" => Some(puzzle.input().into()),
+            "sample" => puzzle.sample().map(Cow::from),
+            "example" => {
+                let path = examples::fixture_dir(puzzle.day()).join("example.txt");
+                if path.exists() {
+                    Some(read(&path)?.into())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+    }
+
+    pub fn get(&self, puzzle: &dyn Puzzle, name: &str) -> Result<Cow<'static, str>, InputError> {
+        let path = self.path(puzzle.day(), name);
+        if path.exists() {
+            return Ok(read(&path)?.into());
+        }
+        Self::embedded(puzzle, name)?.ok_or_else(|| InputError::Missing {
+            day: puzzle.day(),
+            name: name.to_string(),
+            searched: path,
+        })
+    }
+
+    /// Where no source is given, days get their puzzle input
+    pub fn load(
+        &self,
+        puzzle: &dyn Puzzle,
+        source: Option<&InputSource>,
+    ) -> Result<Cow<'static, str>, InputError> {
+        match source {
+            None => self.get(puzzle, "input"),
+            Some(InputSource::Named(name)) => self.get(puzzle, name),
+            Some(InputSource::Stdin) => {
+                let mut buf = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut buf)
+                    .map_err(InputError::Stdin)?;
+                Ok(buf.into())
+            }
+            Some(InputSource::File(path)) => Ok(read(path)?.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    struct Fake;
+
+    impl Solution for Fake {
+        const DAY: u8 = 3;
+        const INPUT: &'static str = "built in";
+
+        type Input = String;
+        type Part1 = String;
+        type Part2 = String;
+
+        fn parse(&self, input: &str) -> color_eyre::Result<String> {
+            Ok(input.to_string())
+        }
+
+        fn part1(&self, input: &String) -> color_eyre::Result<String> {
+            Ok(input.clone())
+        }
+
+        fn part2(&self, input: &String) -> color_eyre::Result<String> {
+            Ok(input.clone())
+        }
+    }
+
+    fn cache_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{test}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_embedded() -> color_eyre::Result<()> {
+        let provider = InputProvider::new(cache_dir("embedded"));
+        assert_eq!("built in", provider.get(&Fake, "input")?);
+        assert_eq!("built in", provider.load(&Fake, None)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_wins() -> color_eyre::Result<()> {
+        let dir = cache_dir("cache");
+        let provider = InputProvider::new(&dir);
+        std::fs::create_dir_all(dir.join("day3"))?;
+        std::fs::write(provider.path(3, "input"), "cached")?;
+        std::fs::write(provider.path(3, "other"), "someone else's")?;
+        assert_eq!("cached", provider.get(&Fake, "input")?);
+        let other = InputSource::Named("other".into());
+        assert_eq!("someone else's", provider.load(&Fake, Some(&other))?);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing() {
+        let dir = cache_dir("missing");
+        let provider = InputProvider::new(&dir);
+        let err = provider.get(&Fake, "sample").unwrap_err();
+        assert!(
+            matches!(&err, InputError::Missing { day: 3, name, searched }
+                if name == "sample" && *searched == dir.join("day3/sample.txt"))
+        );
+        let file = InputSource::File(dir.join("nope.txt"));
+        assert!(matches!(
+            provider.load(&Fake, Some(&file)),
+            Err(InputError::Read { .. })
+        ));
+    }
+}
@@ -1,12 +1,442 @@
-use std::time::Instant;
+mod answers;
+mod bench;
+mod cli;
+mod examples;
+mod inputs;
+mod parallel;
+mod params;
+mod registry;
+mod report;
+mod show;
+mod verify;
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufReader, IsTerminal},
+    time::{Duration, Instant},
+};
+
+use answers::{Answers, Key};
+use aoc_common::{generate::Rng, Part, Puzzle};
+use bench::{Baseline, Entry};
+use clap::Parser;
+use cli::{
+    BenchArgs, Cli, Command, DayArgs, DiffArgs, ExtractArgs, GenerateArgs, InputSource, ParamsArgs,
+    RunArgs, ShowArgs, VerifyArgs,
+};
+use color_eyre::eyre::{bail, eyre, WrapErr};
+use examples::{Choice, Example};
+use inputs::InputProvider;
+use parallel::{Job, Outcome};
+use params::Override;
+use report::Record;
+
+fn print_records(records: &[Record]) {
+    for r in records {
+        match (&r.answer, &r.error) {
+            (Some(answer), _) => println!("Day {} part {}: {answer}", r.day, r.part),
+            (_, Some(e)) => println!("Day {} part {}: {e}", r.day, r.part),
+            _ => {}
+        }
+    }
+    if let Some(first) = records.first() {
+        let solve_ns: u128 = records.iter().filter_map(|r| r.solve_ns).sum();
+        let took = Duration::from_nanos((first.parse_ns + solve_ns) as u64);
+        println!("Day {} took {:?}", first.day, took);
+    }
+}
+
+fn run_command(
+    args: RunArgs,
+    provider: &InputProvider,
+    overrides: &[Override],
+) -> color_eyre::Result<()> {
+    let puzzles = match args.day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| eyre!("no such day {day}"))?],
+        None => {
+            if matches!(args.input, Some(InputSource::Stdin | InputSource::File(_))) {
+                bail!("--input from a file or stdin needs a --day");
+            }
+            registry::all()
+        }
+    };
+
+    let name = InputSource::name(args.input.as_ref());
+    let mut jobs = Vec::new();
+    let mut hashes = HashMap::new();
+    for mut puzzle in puzzles {
+        params::configure(puzzle.as_mut(), &name, overrides)?;
+        match provider.load(puzzle.as_ref(), args.input.as_ref()) {
+            Ok(input) => {
+                hashes.insert(puzzle.day(), report::input_hash(&input));
+                jobs.push(Job {
+                    puzzle,
+                    input: input.into_owned(),
+                });
+            }
+            Err(e) if args.quiet() => eprintln!("Day {}: {e}", puzzle.day()),
+            Err(e) => println!("Day {}: {e}", puzzle.day()),
+        }
+    }
+
+    let parts = args.parts();
+    let timeout = args.timeout.map(Duration::from_secs_f64);
+    let solve = {
+        let (name, parts) = (name.clone(), parts.clone());
+        move |puzzle: &dyn Puzzle, input: &str| report::solve(puzzle, &name, input, &parts)
+    };
+    let mut records = Vec::new();
+    for (day, outcome) in parallel::run(jobs, args.jobs as usize, timeout, solve) {
+        let solved = match outcome {
+            Outcome::Done(solved) => solved,
+            Outcome::TimedOut => {
+                let error = format!("timed out after {:?}", timeout.unwrap_or_default());
+                report::failed(day, &name, &hashes[&day], &parts, &error)
+            }
+            Outcome::Panicked(msg) => {
+                let error = format!("panicked: {msg}");
+                report::failed(day, &name, &hashes[&day], &parts, &error)
+            }
+        };
+        if !args.quiet() {
+            print_records(&solved);
+        }
+        records.extend(solved);
+    }
+
+    if let Some(format) = args.report {
+        let report = report::render(&records, format)?;
+        match &args.output {
+            Some(path) => std::fs::write(path, report)
+                .wrap_err_with(|| format!("writing {}", path.display()))?,
+            None => print!("{report}"),
+        }
+    }
+    Ok(())
+}
+
+fn verify_command(args: VerifyArgs) -> color_eyre::Result<()> {
+    let mut answers = Answers::load(&args.answers)?;
+    let mut checks = Vec::new();
+    for mut puzzle in registry::all()
+        .into_iter()
+        .filter(|p| args.day.is_none_or(|day| p.day() == day))
+    {
+        let example = Example::load(puzzle.day())?;
+        for (part, answer) in example.iter().flat_map(|e| &e.answers) {
+            answers.insert(Key::new(puzzle.day(), *part, "example"), answer.clone());
+        }
+        checks.extend(verify::check(puzzle.as_mut(), &answers, example.as_ref()));
+    }
+    let report = verify::Report(checks);
+    println!("{report}");
+    if report.failures() > 0 {
+        bail!("{} answers did not match", report.failures());
+    }
+    Ok(())
+}
+
+fn bench_command(
+    args: BenchArgs,
+    provider: &InputProvider,
+    overrides: &[Override],
+) -> color_eyre::Result<()> {
+    let puzzles = match args.day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| eyre!("no such day {day}"))?],
+        None => registry::all(),
+    };
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let settings = bench::Settings {
+        warmup: args.warmup,
+        samples: args.samples as usize,
+    };
+
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}  Baseline",
+        "Day", "Phase", "Median", "Min", "Stddev"
+    );
+    let mut entries = Vec::new();
+    let mut regressions = 0;
+    let name = InputSource::name(args.input.as_ref());
+    for mut puzzle in puzzles {
+        params::configure(puzzle.as_mut(), &name, overrides)?;
+        let input = match provider.load(puzzle.as_ref(), args.input.as_ref()) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{:>3}  {e}", puzzle.day());
+                continue;
+            }
+        };
+        for (phase, stats) in bench::bench(puzzle.as_ref(), &input, settings) {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(e) => {
+                    println!("{:>3}  {phase:<5}  {e}", puzzle.day());
+                    continue;
+                }
+            };
+            let compared = match baseline.as_ref().and_then(|b| b.get(puzzle.day(), phase)) {
+                Some(base) => {
+                    let flag = if bench::is_regression(base, &stats, args.threshold / 100.0) {
+                        regressions += 1;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    format!("{:+.1}%{flag}", bench::change(base, &stats) * 100.0)
+                }
+                None => String::new(),
+            };
+            println!(
+                "{:>3}  {phase:<5}  {:>12}  {:>12}  {:>12}  {compared}",
+                puzzle.day(),
+                bench::format_ns(stats.median_ns),
+                bench::format_ns(stats.min_ns),
+                bench::format_ns(stats.stddev_ns),
+            );
+            entries.push(Entry {
+                day: puzzle.day(),
+                phase,
+                stats,
+            });
+        }
+    }
+
+    if let Some(path) = args.save {
+        Baseline { entries }.save(&path)?;
+        println!("Saved baseline to {path}");
+    }
+    if regressions > 0 {
+        bail!("{regressions} phases regressed against the baseline");
+    }
+    Ok(())
+}
+
+fn extract_command(args: ExtractArgs) -> color_eyre::Result<()> {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => (1..=25)
+            .filter(|&day| examples::page_path(day).exists())
+            .collect(),
+    };
+    for day in days {
+        let path = examples::page_path(day);
+        let html = std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("reading {}", path.display()))?;
+        let default = Choice::for_day(day);
+        let choice = Choice {
+            block: args.block.unwrap_or(default.block),
+            answer: args.answer.or(default.answer),
+        };
+        let example = examples::extract(&html, choice).wrap_err_with(|| format!("day {day}"))?;
+        example.save(day)?;
+        let answers: Vec<_> = example
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("part {part} = {}", answers::escape(answer)))
+            .collect();
+        println!("Day {day}: {}", answers.join(", "));
+    }
+    Ok(())
+}
+
+fn show_command(args: ShowArgs) -> color_eyre::Result<()> {
+    let path = examples::page_path(args.day);
+    let html = std::fs::read_to_string(&path)
+        .wrap_err_with(|| format!("no saved puzzle page for day {}", args.day))?;
+    let options = show::Options {
+        width: args.width,
+        color: !args.plain && std::io::stdout().is_terminal(),
+    };
+    let parts = show::render(&html, options);
+    let shown: Vec<_> = match args.part {
+        Some(part) => {
+            let index = match part {
+                Part::One => 0,
+                Part::Two => 1,
+            };
+            vec![parts
+                .get(index)
+                .ok_or_else(|| eyre!("part {part} of day {} isn't on the saved page", args.day))?]
+        }
+        None => parts.iter().collect(),
+    };
+    for (i, text) in shown.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{text}");
+    }
+    Ok(())
+}
+
+fn params_command(args: ParamsArgs, overrides: &[Override]) -> color_eyre::Result<()> {
+    println!("{:>3}  {:<14}  {:>10}  Help", "Day", "Name", "Value");
+    for mut puzzle in registry::all()
+        .into_iter()
+        .filter(|p| args.day.is_none_or(|day| p.day() == day))
+    {
+        params::configure(puzzle.as_mut(), "input", overrides)?;
+        for param in puzzle.params() {
+            println!(
+                "{:>3}  {:<14}  {:>10}  {}",
+                puzzle.day(),
+                param.name,
+                param.value,
+                param.help
+            );
+        }
+    }
+    Ok(())
+}
+
+fn generate_command(args: GenerateArgs) -> color_eyre::Result<()> {
+    let puzzle = registry::find(args.day).ok_or_else(|| eyre!("no such day {}", args.day))?;
+    let mut generator = puzzle
+        .generator()
+        .ok_or_else(|| eyre!("day {} has no generator", args.day))?;
+    for (name, value) in &args.settings {
+        generator
+            .set_param(name, value)
+            .wrap_err_with(|| format!("day {}", args.day))?;
+    }
+    if args.list {
+        println!("{:<14}  {:>10}  Help", "Name", "Value");
+        for param in generator.params() {
+            println!("{:<14}  {:>10}  {}", param.name, param.value, param.help);
+        }
+        return Ok(());
+    }
+    let input = generator
+        .generate(&mut Rng::new(args.seed))
+        .wrap_err_with(|| format!("day {}", args.day))?;
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, input).wrap_err_with(|| format!("writing {}", path.display()))
+        }
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
+
+fn diff_command(args: DiffArgs, overrides: &[Override]) -> color_eyre::Result<()> {
+    let mut mismatches = 0;
+    for mut puzzle in registry::all()
+        .into_iter()
+        .filter(|p| args.day.is_none_or(|day| p.day() == day))
+    {
+        params::configure(puzzle.as_mut(), "input", overrides)?;
+        for diff in puzzle.differentials() {
+            let day = puzzle.day();
+            let mut generator = puzzle
+                .generator()
+                .ok_or_else(|| eyre!("day {day} has no generator"))?;
+            diff.configure(generator.as_mut())?;
+            for (name, value) in &args.settings {
+                generator
+                    .set_param(name, value)
+                    .wrap_err_with(|| format!("day {day}"))?;
+            }
+            let found = diff
+                .find_mismatch(generator.as_ref(), 0..args.seeds)
+                .wrap_err_with(|| format!("day {day}"))?;
+            let Some(mismatch) = found else {
+                println!("Day {day} {}: {} inputs agree", diff.name, args.seeds);
+                continue;
+            };
+            mismatches += 1;
+            println!(
+                "Day {day} {}: seed {} disagrees ({} bytes)",
+                diff.name,
+                mismatch.seed,
+                mismatch.input.len()
+            );
+            println!("  reference: {}", mismatch.reference);
+            println!("  fast:      {}", mismatch.fast);
+            print!("{}", mismatch.input);
+        }
+    }
+    if mismatches > 0 {
+        bail!("{mismatches} checks found inputs they disagree on");
+    }
+    Ok(())
+}
+
+fn day_command(
+    args: DayArgs,
+    provider: &InputProvider,
+    overrides: &[Override],
+) -> color_eyre::Result<()> {
+    let mut puzzle = registry::find(args.day).ok_or_else(|| eyre!("no such day {}", args.day))?;
+    params::configure(
+        puzzle.as_mut(),
+        &InputSource::name(args.input.as_ref()),
+        overrides,
+    )?;
+    let tools = puzzle.tools();
+    let Some(name) = args.tool else {
+        if tools.is_empty() {
+            println!("Day {} has no tools", args.day);
+        }
+        for tool in &tools {
+            println!("{:<14}  {}", tool.name(), tool.about());
+        }
+        return Ok(());
+    };
+    let mut tool = tools
+        .into_iter()
+        .find(|t| t.name() == name)
+        .ok_or_else(|| eyre!("day {} has no tool {name:?}", args.day))?;
+    for (knob, value) in &args.settings {
+        tool.set_param(knob, value)
+            .wrap_err_with(|| format!("day {} {name}", args.day))?;
+    }
+    if args.list {
+        println!("{:<14}  {:>10}  Help", "Name", "Value");
+        for param in tool.params() {
+            println!("{:<14}  {:>10}  {}", param.name, param.value, param.help);
+        }
+        return Ok(());
+    }
+    let output = match &args.input {
+        Some(InputSource::Stdin) => tool.run(&mut std::io::stdin().lock())?,
+        Some(InputSource::File(path)) => {
+            let file = File::open(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+            tool.run(&mut BufReader::new(file))?
+        }
+        named => {
+            let input = provider.load(puzzle.as_ref(), named.as_ref())?;
+            tool.run(&mut input.as_bytes())?
+        }
+    };
+    print!("{output}");
+    Ok(())
+}
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     color_backtrace::install();
 
+    let cli = Cli::parse();
+    let provider = InputProvider::new(cli.inputs);
+    let mut overrides = params::load(&cli.params)?;
+    overrides.extend(cli.overrides);
     let start = Instant::now();
-    // day1::day1_part1();
-    day4::run()?;
+    match cli.command {
+        Command::Run(args) if args.quiet() => return run_command(args, &provider, &overrides),
+        Command::Run(args) => run_command(args, &provider, &overrides)?,
+        Command::Verify(args) => verify_command(args)?,
+        Command::Bench(args) => bench_command(args, &provider, &overrides)?,
+        Command::Extract(args) => extract_command(args)?,
+        Command::Show(args) => return show_command(args),
+        Command::Params(args) => return params_command(args, &overrides),
+        Command::Generate(args) => return generate_command(args),
+        Command::Diff(args) => return diff_command(args, &overrides),
+        Command::Day(args) => return day_command(args, &provider, &overrides),
+    }
     let duration = Instant::now().duration_since(start);
     println!("It took {:?}", duration);
     Ok(())
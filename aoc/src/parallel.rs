@@ -0,0 +1,174 @@
+use std::{
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use aoc_common::Puzzle;
+
+pub struct Job {
+    pub puzzle: Box<dyn Puzzle>,
+    pub input: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    TimedOut,
+    Panicked(String),
+}
+
+pub(crate) fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+        .unwrap_or_default()
+}
+
+/// Runs one job on its own thread so a timeout can walk away from it. Rust
+/// can't kill a thread, so one that times out keeps going in the background
+/// until the process exits.
+fn run_one<T, F>(job: Job, timeout: Option<Duration>, solve: Arc<F>) -> Outcome<T>
+where
+    T: Send + 'static,
+    F: Fn(&dyn Puzzle, &str) -> T + Send + Sync + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let _ = tx.send(solve(job.puzzle.as_ref(), &job.input));
+    });
+    let received = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match received {
+        Ok(result) => Outcome::Done(result),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        // the sender only goes away without sending when the thread panicked
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => Outcome::Panicked(panic_message(payload)),
+            Ok(()) => Outcome::Panicked(String::new()),
+        },
+    }
+}
+
+/// Works through `jobs` on `threads` workers and hands back the outcomes in
+/// the order the jobs were given
+pub fn run<T, F>(
+    jobs: Vec<Job>,
+    threads: usize,
+    timeout: Option<Duration>,
+    solve: F,
+) -> Vec<(u8, Outcome<T>)>
+where
+    T: Send + 'static,
+    F: Fn(&dyn Puzzle, &str) -> T + Send + Sync + 'static,
+{
+    let count = jobs.len();
+    let solve = Arc::new(solve);
+    let queue = Arc::new(Mutex::new(jobs.into_iter().enumerate()));
+    let (tx, rx) = mpsc::channel();
+    let workers: Vec<_> = (0..threads.clamp(1, count.max(1)))
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let solve = Arc::clone(&solve);
+            let tx = tx.clone();
+            thread::spawn(move || loop {
+                let Some((i, job)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let day = job.puzzle.day();
+                let outcome = run_one(job, timeout, Arc::clone(&solve));
+                if tx.send((i, day, outcome)).is_err() {
+                    break;
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    let mut results: Vec<_> = rx.iter().collect();
+    for worker in workers {
+        let _ = worker.join();
+    }
+    results.sort_by_key(|&(i, ..)| i);
+    results
+        .into_iter()
+        .map(|(_, day, outcome)| (day, outcome))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    struct Sleepy<const DAY: u8>;
+
+    impl<const DAY: u8> Solution for Sleepy<DAY> {
+        const DAY: u8 = DAY;
+        const INPUT: &'static str = "";
+
+        type Input = ();
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(&self, _input: &str) -> color_eyre::Result<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &()) -> color_eyre::Result<u32> {
+            Ok(DAY.into())
+        }
+
+        fn part2(&self, _input: &()) -> color_eyre::Result<u32> {
+            Ok(DAY.into())
+        }
+    }
+
+    fn job(puzzle: Box<dyn Puzzle>, input: &str) -> Job {
+        Job {
+            puzzle,
+            input: input.to_string(),
+        }
+    }
+
+    /// The input says how long to sleep, or to panic
+    fn solve(puzzle: &dyn Puzzle, input: &str) -> u8 {
+        match input {
+            "panic" => panic!("day {} blew up", puzzle.day()),
+            ms => thread::sleep(Duration::from_millis(ms.parse().unwrap())),
+        }
+        puzzle.day()
+    }
+
+    #[test]
+    fn test_order_and_outcomes() {
+        let jobs = vec![
+            job(Box::new(Sleepy::<1>), "50"),
+            job(Box::new(Sleepy::<2>), "5000"),
+            job(Box::new(Sleepy::<3>), "panic"),
+            job(Box::new(Sleepy::<4>), "0"),
+        ];
+        let results = run(jobs, 3, Some(Duration::from_millis(500)), solve);
+        assert_eq!(
+            vec![
+                (1, Outcome::Done(1)),
+                (2, Outcome::TimedOut),
+                (3, Outcome::Panicked("day 3 blew up".into())),
+                (4, Outcome::Done(4)),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn test_no_timeout() {
+        let jobs = vec![job(Box::new(Sleepy::<7>), "10")];
+        assert_eq!(vec![(7, Outcome::Done(7))], run(jobs, 8, None, solve));
+    }
+}
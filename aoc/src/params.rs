@@ -0,0 +1,152 @@
+use std::{path::Path, str::FromStr};
+
+use aoc_common::Puzzle;
+use color_eyre::eyre::{eyre, WrapErr};
+
+/// Overrides read from here when the file exists
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/params.txt");
+
+/// `15.row=10` sets day 15's `row` parameter to 10
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub day: u8,
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Override {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || eyre!("expected day.name=value, got {s:?}");
+        let (key, value) = s.split_once('=').ok_or_else(bad)?;
+        let (day, name) = key.trim().split_once('.').ok_or_else(bad)?;
+        let day = day.parse().map_err(|_| bad())?;
+        if name.is_empty() {
+            return Err(bad());
+        }
+        Ok(Override {
+            day,
+            name: name.to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// One override per line, `#` starts a comment
+pub fn parse_file(contents: &str) -> color_eyre::Result<Vec<Override>> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.split('#').next().unwrap_or_default().trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| line.parse().wrap_err_with(|| format!("line {}", i + 1)))
+        .collect()
+}
+
+/// A missing file at the default path just means no overrides
+pub fn load(path: &Path) -> color_eyre::Result<Vec<Override>> {
+    if !path.exists() && path == Path::new(DEFAULT_PATH) {
+        return Ok(Vec::new());
+    }
+    let contents =
+        std::fs::read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+    parse_file(&contents).wrap_err_with(|| format!("in {}", path.display()))
+}
+
+/// The sample and the puzzle example are sized differently from the real
+/// input, so they get the day's sample parameters
+pub fn uses_sample_params(input: &str) -> bool {
+    matches!(input, "sample" | "example")
+}
+
+/// Errors name the override the way it was written, `9.knots=0`
+pub fn set(puzzle: &mut dyn Puzzle, name: &str, value: &str) -> color_eyre::Result<()> {
+    puzzle
+        .set_param(name, value)
+        .wrap_err_with(|| format!("{}.{name}={value}", puzzle.day()))
+}
+
+/// Sample parameters first when the input needs them, then the overrides
+/// for this day in the order given
+pub fn configure(
+    puzzle: &mut dyn Puzzle,
+    input: &str,
+    overrides: &[Override],
+) -> color_eyre::Result<()> {
+    if uses_sample_params(input) {
+        for &(name, value) in puzzle.sample_params() {
+            set(puzzle, name, value)?;
+        }
+    }
+    let day = puzzle.day();
+    for o in overrides.iter().filter(|o| o.day == day) {
+        set(puzzle, &o.name, &o.value)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            Override {
+                day: 15,
+                name: "row".into(),
+                value: "10".into()
+            },
+            "15.row=10".parse().unwrap()
+        );
+        assert!("row=10".parse::<Override>().is_err());
+        assert!("x.row=10".parse::<Override>().is_err());
+        assert!("15.=10".parse::<Override>().is_err());
+    }
+
+    #[test]
+    fn test_parse_file() -> color_eyre::Result<()> {
+        let overrides = parse_file("# sample sized\n15.row = 10\n\n15.max=20 # both axes\n")?;
+        assert_eq!(2, overrides.len());
+        assert_eq!("10", overrides[0].value);
+        assert_eq!("max", overrides[1].name);
+        let err = parse_file("15.row=10\nnonsense\n").unwrap_err();
+        assert_eq!("line 2", err.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configure() -> color_eyre::Result<()> {
+        let value = |puzzle: &dyn Puzzle, name| {
+            puzzle
+                .params()
+                .into_iter()
+                .find(|p| p.name == name)
+                .map(|p| p.value)
+        };
+        let overrides = vec!["15.max=30".parse()?, "11.relief=1".parse()?];
+        let mut day15 = registry::find(15).unwrap();
+        configure(day15.as_mut(), "example", &overrides)?;
+        assert_eq!(Some("10".to_string()), value(day15.as_ref(), "row"));
+        assert_eq!(Some("30".to_string()), value(day15.as_ref(), "max"));
+
+        let mut day15 = registry::find(15).unwrap();
+        configure(day15.as_mut(), "input", &[])?;
+        assert_eq!(Some("2000000".to_string()), value(day15.as_ref(), "row"));
+
+        let mut day1 = registry::find(1).unwrap();
+        let err = configure(day1.as_mut(), "input", &["1.x=2".parse()?]).unwrap_err();
+        assert_eq!("1.x=2", err.to_string());
+
+        let mut day9 = registry::find(9).unwrap();
+        let err = configure(day9.as_mut(), "input", &["9.knots=0".parse()?]).unwrap_err();
+        assert_eq!("9.knots=0", err.to_string());
+        assert_eq!(
+            "the rope needs at least one knot",
+            err.root_cause().to_string()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::Puzzle;
+
+/// Every day of the calendar, in order
+pub fn all() -> Vec<Box<dyn Puzzle>> {
+    vec![
+        Box::new(day1::Day1),
+        Box::new(day2::Day2::default()),
+        Box::new(day3::Day3),
+        Box::new(day4::Day4),
+        Box::new(day5::Day5),
+        Box::new(day6::Day6),
+        Box::new(day7::Day7::default()),
+        Box::new(day8::Day8),
+        Box::new(day9::Day9::default()),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11::default()),
+        Box::new(day12::Day12),
+        Box::new(day13::Day13),
+        Box::new(day14::Day14),
+        Box::new(day15::Day15::default()),
+        Box::new(day16::Day16::default()),
+        Box::new(day17::Day17),
+        Box::new(day18::Day18),
+        Box::new(day19::Day19),
+        Box::new(day20::Day20),
+        Box::new(day21::Day21),
+        Box::new(day22::Day22),
+        Box::new(day23::Day23),
+        Box::new(day24::Day24),
+        Box::new(day25::Day25),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn Puzzle>> {
+    all().into_iter().find(|p| p.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{generate::Rng, Part};
+
+    use super::*;
+
+    #[test]
+    fn test_every_day_registered_once() {
+        let days: Vec<_> = all().iter().map(|p| p.day()).collect();
+        assert_eq!((1..=25).collect::<Vec<_>>(), days);
+    }
+
+    /// Generator knobs, by day, that keep the slow days quick in a debug
+    /// build
+    const SMALL: &[(u8, &str, &str)] = &[
+        (12, "rows", "10"),
+        (12, "cols", "30"),
+        (15, "sensors", "10"),
+        (15, "max", "60"),
+        (16, "valves", "20"),
+        (16, "working", "8"),
+    ];
+
+    /// Day parameters the small inputs need in place of the defaults
+    const SMALL_PARAMS: &[(u8, &str, &str)] = &[(15, "row", "30"), (15, "max", "60")];
+
+    /// Whatever a generator makes, its day has to parse and solve
+    #[test]
+    fn test_generated_inputs_solve() -> color_eyre::Result<()> {
+        for mut puzzle in all() {
+            let day = puzzle.day();
+            let Some(mut generator) = puzzle.generator() else {
+                continue;
+            };
+            for &(_, name, value) in SMALL.iter().filter(|(d, ..)| *d == day) {
+                generator.set_param(name, value)?;
+            }
+            for &(_, name, value) in SMALL_PARAMS.iter().filter(|(d, ..)| *d == day) {
+                puzzle.set_param(name, value)?;
+            }
+            for seed in 0..3 {
+                let input = generator.generate(&mut Rng::new(seed))?;
+                let parsed = puzzle.parse(&input)?;
+                for part in Part::both() {
+                    let answer = puzzle.part(&parsed, part);
+                    assert!(
+                        answer.is_ok(),
+                        "day {day} part {part}, seed {seed}: {answer:?}"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use std::{fmt::Write, time::Instant};
+
+use aoc_common::{Part, Puzzle};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+/// One part of one day, as solved by `aoc run`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// `input`, `sample`, `-` for stdin or a file path
+    pub input: String,
+    pub input_hash: String,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    /// Shared by both parts, which solve from the same parse
+    pub parse_ns: u128,
+    /// Not there when parsing failed
+    pub solve_ns: Option<u128>,
+}
+
+impl Record {
+    fn new(day: u8, part: Part, input: &str, input_hash: &str) -> Self {
+        Record {
+            day,
+            part: match part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            input: input.to_string(),
+            input_hash: input_hash.to_string(),
+            answer: None,
+            error: None,
+            parse_ns: 0,
+            solve_ns: None,
+        }
+    }
+}
+
+/// FNV-1a, so the hash stays the same across builds and toolchains
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Parses once and times each part on top of that
+pub fn solve(puzzle: &dyn Puzzle, name: &str, input: &str, parts: &[Part]) -> Vec<Record> {
+    let input_hash = input_hash(input);
+    let record = |part: Part, parse_ns| Record {
+        parse_ns,
+        ..Record::new(puzzle.day(), part, name, &input_hash)
+    };
+
+    let start = Instant::now();
+    let parsed = puzzle.parse(input);
+    let parse_ns = start.elapsed().as_nanos();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return parts
+                .iter()
+                .map(|&part| Record {
+                    error: Some(format!("parse failed: {e}")),
+                    ..record(part, parse_ns)
+                })
+                .collect();
+        }
+    };
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = puzzle.part(&parsed, part);
+            let solve_ns = Some(start.elapsed().as_nanos());
+            match answer {
+                Ok(answer) => Record {
+                    answer: Some(answer.to_string()),
+                    solve_ns,
+                    ..record(part, parse_ns)
+                },
+                Err(e) => Record {
+                    error: Some(e.to_string()),
+                    solve_ns,
+                    ..record(part, parse_ns)
+                },
+            }
+        })
+        .collect()
+}
+
+/// Stands in for the parts of a day that never got an answer
+pub fn failed(day: u8, name: &str, input_hash: &str, parts: &[Part], error: &str) -> Vec<Record> {
+    parts
+        .iter()
+        .map(|&part| Record {
+            error: Some(error.to_string()),
+            ..Record::new(day, part, name, input_hash)
+        })
+        .collect()
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,part,input,input_hash,answer,error,parse_ns,solve_ns\n");
+    for r in records {
+        let solve_ns = r.solve_ns.map(|ns| ns.to_string()).unwrap_or_default();
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{}",
+            r.day,
+            r.part,
+            csv_field(&r.input),
+            r.input_hash,
+            csv_field(r.answer.as_deref().unwrap_or_default()),
+            csv_field(r.error.as_deref().unwrap_or_default()),
+            r.parse_ns,
+            solve_ns
+        )
+        .unwrap();
+    }
+    csv
+}
+
+pub fn render(records: &[Record], format: Format) -> color_eyre::Result<String> {
+    Ok(match format {
+        Format::Json => serde_json::to_string_pretty(records)? + "\n",
+        Format::Csv => to_csv(records),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+    use color_eyre::eyre::bail;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 10;
+        const INPUT: &'static str = "a\nb\n";
+
+        type Input = Vec<String>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(&self, input: &str) -> color_eyre::Result<Vec<String>> {
+            if input.is_empty() {
+                bail!("empty input");
+            }
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part1(&self, input: &Vec<String>) -> color_eyre::Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part2(&self, input: &Vec<String>) -> color_eyre::Result<String> {
+            Ok(input.join("\n"))
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        // reference values for 64-bit FNV-1a
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let records = solve(&Lines, "input", Lines::INPUT, &Part::both());
+        assert_eq!(2, records.len());
+        assert_eq!(Some("2".to_string()), records[0].answer);
+        assert_eq!(Some("a\nb".to_string()), records[1].answer);
+        assert_eq!(2, records[1].part);
+        assert!(records.iter().all(|r| r.solve_ns.is_some()));
+
+        let records = solve(&Lines, "-", "", &[Part::Two]);
+        assert_eq!(
+            Some("parse failed: empty input".to_string()),
+            records[0].error
+        );
+        assert_eq!(None, records[0].solve_ns);
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut records = solve(&Lines, "my, input", Lines::INPUT, &Part::both());
+        for r in &mut records {
+            r.parse_ns = 5;
+            r.solve_ns = Some(7);
+        }
+        assert_eq!(
+            "day,part,input,input_hash,answer,error,parse_ns,solve_ns
+10,1,\"my, input\",78ed6781f136a14e,2,,5,7
+10,2,\"my, input\",78ed6781f136a14e,\"a
+b\",,5,7
+",
+            to_csv(&records)
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() -> color_eyre::Result<()> {
+        let records = solve(&Lines, "input", Lines::INPUT, &Part::both());
+        let json = render(&records, Format::Json)?;
+        assert_eq!(records, serde_json::from_str::<Vec<Record>>(&json)?);
+        Ok(())
+    }
+}
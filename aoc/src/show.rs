@@ -0,0 +1,311 @@
+use owo_colors::{OwoColorize, Style};
+
+use crate::examples::unescape_html;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub width: usize,
+    pub color: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: &'a str, attrs: &'a str },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        tokens.push(match tag.strip_prefix('/') {
+            Some(name) => Token::Close(name.trim()),
+            None => {
+                let (name, attrs) = tag.split_once(' ').unwrap_or((tag, ""));
+                Token::Open { name, attrs }
+            }
+        });
+        rest = &rest[start + end + 1..];
+    }
+    tokens
+}
+
+/// Runs of whitespace, newlines included, become a single space
+fn collapse_whitespace(s: &str) -> String {
+    let mut collapsed = String::with_capacity(s.len());
+    for c in s.chars() {
+        if !c.is_whitespace() {
+            collapsed.push(c);
+        } else if !collapsed.ends_with(' ') {
+            collapsed.push(' ');
+        }
+    }
+    collapsed
+}
+
+/// Walks one article, keeping track of which inline styles are open and
+/// collecting finished lines
+struct Renderer {
+    options: Options,
+    lines: Vec<String>,
+    buf: String,
+    em: usize,
+    star: usize,
+    code: usize,
+    pre: bool,
+    heading: bool,
+}
+
+impl Renderer {
+    fn new(options: Options) -> Self {
+        Self {
+            options,
+            lines: Vec::new(),
+            buf: String::new(),
+            em: 0,
+            star: 0,
+            code: 0,
+            pre: false,
+            heading: false,
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = unescape_html(text);
+        let text = if self.pre {
+            text
+        } else {
+            collapse_whitespace(&text)
+        };
+        if self.options.color {
+            let mut style = Style::new();
+            if self.code > 0 && !self.pre {
+                style = style.bright_white();
+            }
+            if self.em > 0 {
+                style = style.bold().bright_white();
+            }
+            if self.star > 0 {
+                style = style.bold().yellow();
+            }
+            if self.heading {
+                style = style.bold().bright_white();
+            }
+            // style each line on its own so wrapping and indenting never
+            // split an escape sequence
+            let styled: Vec<_> = text
+                .split('\n')
+                .map(|line| line.style(style).to_string())
+                .collect();
+            self.buf.push_str(&styled.join("\n"));
+        } else if self.em > 0 && !self.pre {
+            self.buf.push_str(&format!("*{text}*"));
+        } else {
+            self.buf.push_str(&text);
+        }
+    }
+
+    /// Wraps whatever has been collected as one paragraph
+    fn flush(&mut self, initial: &str, subsequent: &str) {
+        let text = std::mem::take(&mut self.buf);
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let options = textwrap::Options::new(self.options.width)
+            .initial_indent(initial)
+            .subsequent_indent(subsequent);
+        self.lines
+            .extend(textwrap::wrap(text, options).into_iter().map(String::from));
+    }
+
+    fn blank(&mut self) {
+        if self.lines.last().is_some_and(|l| !l.is_empty()) {
+            self.lines.push(String::new());
+        }
+    }
+
+    fn token(&mut self, token: Token) {
+        match token {
+            Token::Text(text) => self.text(text),
+            Token::Open { name: "em", attrs } => {
+                if attrs.contains("star") {
+                    self.star += 1;
+                } else {
+                    self.em += 1;
+                }
+            }
+            Token::Close("em") => {
+                if self.star > 0 {
+                    self.star -= 1;
+                } else {
+                    self.em = self.em.saturating_sub(1);
+                }
+            }
+            Token::Open { name: "code", .. } => self.code += 1,
+            Token::Close("code") => self.code = self.code.saturating_sub(1),
+            Token::Open {
+                name: "p" | "li" | "ul" | "h2" | "pre",
+                ..
+            } if !self.pre => {
+                // anything left over is the whitespace between blocks
+                self.flush("", "");
+                self.pre = matches!(token, Token::Open { name: "pre", .. });
+                self.heading = matches!(token, Token::Open { name: "h2", .. });
+            }
+            Token::Close("pre") => {
+                self.pre = false;
+                let text = std::mem::take(&mut self.buf);
+                self.lines.extend(
+                    text.trim_end_matches('\n')
+                        .split('\n')
+                        .map(|line| format!("    {line}")),
+                );
+                self.blank();
+            }
+            Token::Close("h2") => {
+                self.flush("", "");
+                self.heading = false;
+                self.blank();
+            }
+            Token::Close("p") => {
+                self.flush("", "");
+                self.blank();
+            }
+            Token::Close("li") => self.flush("  - ", "    "),
+            Token::Close("ul") => self.blank(),
+            _ => {}
+        }
+    }
+}
+
+/// The text of every part unlocked on the page, in order
+pub fn render(html: &str, options: Options) -> Vec<String> {
+    crate::examples::between(html, r#"<article class="day-desc">"#, "</article>")
+        .into_iter()
+        .map(|article| {
+            let mut renderer = Renderer::new(options);
+            for token in tokens(article) {
+                renderer.token(token);
+            }
+            renderer.flush("", "");
+            renderer.lines.join("\n").trim_end().to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 4: Camp Cleanup ---</h2><p>Every section has a unique <em>ID number</em>, and
+each Elf is assigned a range of <a href="x">section IDs</a>.</p>
+<pre><code>2-4,6-8
+2-3,&lt;4-5
+</code></pre>
+<ul>
+<li>The first pair has <code>2-4</code>.</li>
+<li>The second pair has <code>2-3</code>.</li>
+</ul>
+<p>There are <code><em>2</em></code> such pairs.</p>
+</article>
+<p>Answer: <input type="text"></p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>It seems like there is still quite a bit of duplicate work planned.</p>
+</article>
+</main>"#;
+
+    const PLAIN: Options = Options {
+        width: 40,
+        color: false,
+    };
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            vec![
+                Token::Open {
+                    name: "em",
+                    attrs: r#"class="star""#
+                },
+                Token::Text("*"),
+                Token::Close("em"),
+                Token::Text(" x"),
+            ],
+            tokens(r#"<em class="star">*</em> x"#)
+        );
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let parts = render(PAGE, PLAIN);
+        assert_eq!(2, parts.len());
+        assert_eq!(
+            "--- Day 4: Camp Cleanup ---
+
+Every section has a unique *ID number*,
+and each Elf is assigned a range of
+section IDs.
+
+    2-4,6-8
+    2-3,<4-5
+
+  - The first pair has 2-4.
+  - The second pair has 2-3.
+
+There are *2* such pairs.",
+            parts[0]
+        );
+        assert_eq!(
+            "--- Part Two ---
+
+It seems like there is still quite a bit
+of duplicate work planned.",
+            parts[1]
+        );
+    }
+
+    #[test]
+    fn test_render_color() {
+        let parts = render(
+            PAGE,
+            Options {
+                color: true,
+                ..PLAIN
+            },
+        );
+        assert!(parts[0].contains(
+            &"ID number"
+                .style(Style::new().bold().bright_white())
+                .to_string()
+        ));
+        // code blocks keep their layout
+        assert!(parts[0].contains("\n    2-3,<4-5\n"));
+    }
+
+    #[test]
+    fn test_saved_pages_render() {
+        for day in 1..=25 {
+            let Ok(html) = std::fs::read_to_string(crate::examples::page_path(day)) else {
+                continue;
+            };
+            let parts = render(&html, PLAIN);
+            assert!(!parts.is_empty(), "day {day}");
+            assert!(parts[0].contains(&format!("--- Day {day}:")), "day {day}");
+            assert!(!parts[0].contains("<p>"), "day {day}");
+        }
+    }
+}
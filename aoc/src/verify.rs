@@ -0,0 +1,303 @@
+use std::{
+    fmt::Display,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use aoc_common::{Answer, Part, Puzzle};
+use color_eyre::eyre::eyre;
+
+use crate::{
+    answers::{escape, Answers, Key},
+    examples::Example,
+    params,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// Nothing recorded yet; this is what the solution said
+    Missing {
+        actual: String,
+    },
+    /// Listed as failing in the answers, and still doing so
+    KnownFailure {
+        why: String,
+        actual: String,
+    },
+    /// Listed as failing, but it passes now, so the list is out of date
+    Fixed,
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub key: Key,
+    pub outcome: Outcome,
+}
+
+/// The inputs each day gets checked against, by name. `example` is the one
+/// extracted from the saved puzzle page, if there is one.
+pub fn named_inputs<'a>(
+    puzzle: &dyn Puzzle,
+    example: Option<&'a Example>,
+) -> Vec<(&'static str, &'a str)> {
+    let mut inputs = vec![("input", puzzle.input())];
+    if let Some(sample) = puzzle.sample() {
+        inputs.push(("sample", sample));
+    }
+    if let Some(example) = example {
+        inputs.push(("example", example.input.as_str()));
+    }
+    inputs
+}
+
+/// Some of the older days still panic on input they weren't written for
+fn solve(puzzle: &dyn Puzzle, input: &str, part: Part) -> color_eyre::Result<Answer> {
+    catch_unwind(AssertUnwindSafe(|| puzzle.solve(input, part))).unwrap_or_else(|panic| {
+        let msg = panic
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_default();
+        Err(eyre!("panicked: {msg}"))
+    })
+}
+
+/// Answers are recorded for the day's default parameters, and its sample
+/// parameters on the sample and example
+pub fn check(puzzle: &mut dyn Puzzle, answers: &Answers, example: Option<&Example>) -> Vec<Check> {
+    let defaults = puzzle.params();
+    let mut checks = Vec::new();
+    for (name, input) in named_inputs(puzzle, example) {
+        let pairs: Vec<_> = if params::uses_sample_params(name) {
+            puzzle.sample_params().to_vec()
+        } else {
+            defaults
+                .iter()
+                .map(|p| (p.name, p.value.as_str()))
+                .collect()
+        };
+        for (param, value) in pairs {
+            // the day's own parameters always fit
+            params::set(puzzle, param, value).expect("sample parameter");
+        }
+        for part in Part::both() {
+            let key = Key::new(puzzle.day(), part, name);
+            let actual = match solve(puzzle, input, part) {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("error: {e}"),
+            };
+            let outcome = match (answers.get(&key), answers.failing(&key)) {
+                (Some(expected), Some(_)) if expected == actual => Outcome::Fixed,
+                (_, Some(why)) => Outcome::KnownFailure {
+                    why: why.to_string(),
+                    actual,
+                },
+                (Some(expected), _) if expected == actual => Outcome::Pass,
+                (Some(expected), _) => Outcome::Fail {
+                    expected: expected.to_string(),
+                    actual,
+                },
+                (None, _) => Outcome::Missing { actual },
+            };
+            checks.push(Check { key, outcome });
+        }
+    }
+    for p in &defaults {
+        params::set(puzzle, p.name, &p.value).expect("default parameter");
+    }
+    checks
+}
+
+pub struct Report(pub Vec<Check>);
+
+impl Report {
+    pub fn count(&self, f: impl Fn(&Outcome) -> bool) -> usize {
+        self.0.iter().filter(|c| f(&c.outcome)).count()
+    }
+
+    /// Known failures don't count, but ones that have started passing do
+    pub fn failures(&self) -> usize {
+        self.count(|o| matches!(o, Outcome::Fail { .. } | Outcome::Fixed))
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>3}  {:>4}  {:<8}  {:<7}  Detail",
+            "Day", "Part", "Input", "Status"
+        )?;
+        for check in &self.0 {
+            let (status, detail) = match &check.outcome {
+                Outcome::Pass => ("pass", String::new()),
+                Outcome::Fail { expected, actual } => (
+                    "FAIL",
+                    format!("expected {} got {}", escape(expected), escape(actual)),
+                ),
+                Outcome::Missing { actual } => ("missing", escape(actual)),
+                Outcome::KnownFailure { why, actual } => {
+                    ("known", format!("{why}: {}", escape(actual)))
+                }
+                Outcome::Fixed => (
+                    "FIXED",
+                    "passes now; take it out of the known failures".to_string(),
+                ),
+            };
+            writeln!(
+                f,
+                "{:>3}  {:>4}  {:<8}  {:<7}  {}",
+                check.key.day, check.key.part, check.key.input, status, detail
+            )?;
+        }
+        write!(
+            f,
+            "{} passed, {} failed, {} known to fail, {} missing",
+            self.count(|o| *o == Outcome::Pass),
+            self.failures(),
+            self.count(|o| matches!(o, Outcome::KnownFailure { .. })),
+            self.count(|o| matches!(o, Outcome::Missing { .. }))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u8 = 1;
+        const INPUT: &'static str = "7";
+        const SAMPLE: Option<&'static str> = Some("3");
+
+        type Input = i64;
+        type Part1 = i64;
+        type Part2 = i64;
+
+        fn parse(&self, input: &str) -> color_eyre::Result<i64> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(&self, input: &i64) -> color_eyre::Result<i64> {
+            Ok(*input)
+        }
+
+        fn part2(&self, _input: &i64) -> color_eyre::Result<i64> {
+            panic!("boom")
+        }
+    }
+
+    /// Multiplies the input, by 2 for the sample
+    struct Scaled(i64);
+
+    impl Solution for Scaled {
+        const DAY: u8 = 2;
+        const INPUT: &'static str = "7";
+        const SAMPLE: Option<&'static str> = Some("3");
+        const SAMPLE_PARAMS: &'static [(&'static str, &'static str)] = &[("by", "2")];
+
+        type Input = i64;
+        type Part1 = i64;
+        type Part2 = i64;
+
+        fn parse(&self, input: &str) -> color_eyre::Result<i64> {
+            Ok(input.parse::<i64>()? * self.0)
+        }
+
+        fn part1(&self, input: &i64) -> color_eyre::Result<i64> {
+            Ok(*input)
+        }
+
+        fn part2(&self, input: &i64) -> color_eyre::Result<i64> {
+            Ok(*input)
+        }
+
+        fn params(&self) -> Vec<aoc_common::Param> {
+            vec![aoc_common::Param::new("by", "", self.0)]
+        }
+
+        fn set_param(&mut self, _name: &str, value: &str) -> color_eyre::Result<()> {
+            self.0 = value.parse()?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_check_sample_params() -> color_eyre::Result<()> {
+        let answers = Answers::parse("2 1 input 70\n2 1 sample 6\n2 1 example 10\n")?;
+        let example = Example {
+            input: "5".into(),
+            answers: Vec::new(),
+        };
+        let mut puzzle = Scaled(10);
+        let checks = check(&mut puzzle, &answers, Some(&example));
+        let passed: Vec<_> = checks
+            .iter()
+            .filter(|c| c.outcome == Outcome::Pass)
+            .map(|c| c.key.input.as_str())
+            .collect();
+        assert_eq!(vec!["input", "sample", "example"], passed);
+        assert_eq!(10, puzzle.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_outcomes() -> color_eyre::Result<()> {
+        let answers = Answers::parse("1 1 input 7\n1 1 sample 4\n1 2 input 7\n")?;
+        let checks = check(&mut Echo, &answers, None);
+        let outcomes: Vec<_> = checks.iter().map(|c| &c.outcome).collect();
+        assert_eq!(Outcome::Pass, *outcomes[0]);
+        assert!(
+            matches!(outcomes[1], Outcome::Fail { actual, .. } if actual == "error: panicked: boom")
+        );
+        assert_eq!(
+            Outcome::Fail {
+                expected: "4".into(),
+                actual: "3".into()
+            },
+            *outcomes[2]
+        );
+        assert!(matches!(outcomes[3], Outcome::Missing { .. }));
+        Ok(())
+    }
+
+    #[test]
+    fn test_known_failures() -> color_eyre::Result<()> {
+        let answers = Answers::parse(
+            "1 1 input 7\nfails 1 1 input not any more\n1 2 input 7\nfails 1 2 input boom\n",
+        )?;
+        let checks = check(&mut Echo, &answers, None);
+        assert_eq!(Outcome::Fixed, checks[0].outcome);
+        assert!(matches!(
+            &checks[1].outcome,
+            Outcome::KnownFailure { why, .. } if why == "boom"
+        ));
+        let report = Report(checks);
+        assert_eq!(1, report.failures());
+        assert!(report
+            .to_string()
+            .ends_with("0 passed, 1 failed, 1 known to fail, 2 missing"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_example() {
+        let example = Example {
+            input: "5".into(),
+            answers: vec![(Part::One, "5".into())],
+        };
+        let checks = check(&mut Echo, &Answers::default(), Some(&example));
+        let inputs: Vec<_> = checks.iter().map(|c| c.key.input.as_str()).collect();
+        assert_eq!(
+            vec!["input", "input", "sample", "sample", "example", "example"],
+            inputs
+        );
+    }
+}
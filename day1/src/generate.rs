@@ -0,0 +1,57 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use eyre::{bail, ensure};
+
+/// Elves carrying a few snacks each
+pub(crate) struct InputGenerator {
+    elves: usize,
+    items: usize,
+    calories: i64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            elves: 250,
+            items: 15,
+            calories: 60_000,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("elves", "how many elves", self.elves),
+            Param::new("items", "most snacks one elf carries", self.items),
+            Param::new("calories", "most calories in one snack", self.calories),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "elves" => self.elves = parse_param(name, value)?,
+            "items" => self.items = parse_param(name, value)?,
+            "calories" => self.calories = parse_param(name, value)?,
+            _ => bail!("day 1's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> eyre::Result<String> {
+        ensure!(self.elves > 0, "there has to be at least one elf");
+        ensure!(self.items > 0, "every elf carries at least one snack");
+        ensure!(self.calories > 0, "snacks have at least 1 calorie");
+        let elves: Vec<String> = (0..self.elves)
+            .map(|_| {
+                let items = 1 + rng.index(self.items);
+                (0..items)
+                    .map(|_| format!("{}\n", rng.range(1..=self.calories)))
+                    .collect()
+            })
+            .collect();
+        Ok(elves.join("\n"))
+    }
+}
@@ -0,0 +1,310 @@
+//! Reading elves off a ledger one at a time, so the ledger can be far
+//! bigger than memory. Only the elf being read and the ones being kept
+//! are held on to.
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    fmt::Display,
+    io::BufRead,
+};
+
+use aoc_common::parse::{parse_all, ParseError};
+
+use crate::parser;
+
+/// One elf's snacks, and where it came in the ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Counts from 0
+    pub index: usize,
+    pub items: Vec<i64>,
+    calories: i64,
+}
+
+impl Elf {
+    pub fn new(index: usize, items: Vec<i64>) -> Self {
+        let calories = items.iter().sum();
+        Self {
+            index,
+            items,
+            calories,
+        }
+    }
+
+    pub fn calories(&self) -> i64 {
+        self.calories
+    }
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+    Io(std::io::Error),
+    /// A line that isn't a number, numbered from the top of the ledger
+    Parse(ParseError),
+}
+
+impl Display for LedgerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LedgerError::Io(e) => write!(f, "couldn't read the ledger: {e}"),
+            LedgerError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+impl From<std::io::Error> for LedgerError {
+    fn from(e: std::io::Error) -> Self {
+        LedgerError::Io(e)
+    }
+}
+
+/// The elves in a ledger, in order. Elves are separated by a blank line,
+/// which the last one can do without.
+pub struct Ledger<R> {
+    reader: R,
+    line: String,
+    /// Lines read so far
+    line_number: usize,
+    next_index: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Ledger<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            next_index: 0,
+            done: false,
+        }
+    }
+
+    fn next_elf(&mut self) -> Result<Option<Elf>, LedgerError> {
+        let mut items = Vec::new();
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                self.done = true;
+                if items.is_empty() {
+                    return Ok(None);
+                }
+                break;
+            }
+            self.line_number += 1;
+            let line = self.line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                break;
+            }
+            let item = parse_all(line, parser::integer).map_err(|mut e| {
+                e.line = self.line_number;
+                LedgerError::Parse(e)
+            })?;
+            items.push(item);
+        }
+        let elf = Elf::new(self.next_index, items);
+        self.next_index += 1;
+        Ok(Some(elf))
+    }
+}
+
+impl<R: BufRead> Iterator for Ledger<R> {
+    type Item = Result<Elf, LedgerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let elf = self.next_elf();
+        if elf.is_err() {
+            self.done = true;
+        }
+        elf.transpose()
+    }
+}
+
+/// The `k` biggest things pushed into it, holding on to no more than `k` at
+/// a time
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if self.heap.peek().is_some_and(|Reverse(least)| *least < item) {
+            self.heap.pop();
+            self.heap.push(Reverse(item));
+        }
+    }
+
+    /// Biggest first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(t)| t)
+            .collect()
+    }
+}
+
+/// Most calories first; the elf that came first wins a tie
+struct Ranked(Elf);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .calories
+            .cmp(&other.0.calories)
+            .then(other.0.index.cmp(&self.0.index))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+/// The `n` elves carrying the most calories, most first, read straight off
+/// `reader`
+pub fn top_elves(reader: impl BufRead, n: usize) -> Result<Vec<Elf>, LedgerError> {
+    let mut top = TopK::new(n);
+    for elf in Ledger::new(reader) {
+        top.push(Ranked(elf?));
+    }
+    Ok(top
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Ranked(elf)| elf)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, Read};
+
+    use super::*;
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn test_ledger() -> Result<(), LedgerError> {
+        let elves = Ledger::new(INPUT.as_bytes()).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(5, elves.len());
+        assert_eq!(Elf::new(2, vec![5000, 6000]), elves[2]);
+        assert_eq!(10000, elves[4].calories());
+        // a trailing blank line, or Windows line endings, make no difference
+        let crlf = format!("{INPUT}\n").replace('\n', "\r\n");
+        assert_eq!(
+            elves,
+            Ledger::new(crlf.as_bytes()).collect::<Result<Vec<_>, _>>()?
+        );
+        assert_eq!(0, Ledger::new("".as_bytes()).count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_line() {
+        let mut ledger = Ledger::new("1000\n\n20x0\n\n3000\n".as_bytes());
+        assert!(ledger.next().unwrap().is_ok());
+        let Some(Err(LedgerError::Parse(e))) = ledger.next() else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((3, 3), (e.line, e.column));
+        assert!(ledger.next().is_none());
+    }
+
+    #[test]
+    fn test_top_k() {
+        let mut top = TopK::new(3);
+        for n in [5, 1, 9, 3, 7, 9, 2] {
+            top.push(n);
+        }
+        assert_eq!(vec![9, 9, 7], top.into_sorted_vec());
+        let mut none = TopK::new(0);
+        none.push(1);
+        assert!(none.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn test_top_elves() -> Result<(), LedgerError> {
+        let top = top_elves(INPUT.as_bytes(), 3)?;
+        assert_eq!(
+            vec![3, 2, 4],
+            top.iter().map(|e| e.index).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![7000, 8000, 9000], top[0].items);
+        // ties go to whoever came first
+        let top = top_elves("5\n\n2\n3\n\n4\n1\n".as_bytes(), 2)?;
+        assert_eq!(vec![0, 1], top.iter().map(|e| e.index).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    /// The sample ledger over and over, without ever having it all in
+    /// memory
+    struct Repeated {
+        times: usize,
+        at: usize,
+    }
+
+    impl Repeated {
+        const TEXT: &'static [u8] =
+            b"1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n\n";
+
+        fn new(times: usize) -> Self {
+            Self {
+                times,
+                at: Self::TEXT.len(),
+            }
+        }
+    }
+
+    impl Read for Repeated {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.at == Self::TEXT.len() && self.times > 0 {
+                self.times -= 1;
+                self.at = 0;
+            }
+            let n = (Self::TEXT.len() - self.at).min(buf.len());
+            buf[..n].copy_from_slice(&Self::TEXT[self.at..self.at + n]);
+            self.at += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_streams() -> Result<(), LedgerError> {
+        let top = top_elves(BufReader::with_capacity(64, Repeated::new(20_000)), 2)?;
+        assert_eq!(
+            vec![24000, 24000],
+            top.iter().map(Elf::calories).collect::<Vec<_>>()
+        );
+        assert_eq!(vec![3, 8], top.iter().map(|e| e.index).collect::<Vec<_>>());
+        assert_eq!(
+            100_000,
+            Ledger::new(BufReader::new(Repeated::new(20_000))).count()
+        );
+        Ok(())
+    }
+}
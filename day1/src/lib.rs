@@ -1,21 +1,62 @@
 #![allow(unused)]
+mod generate;
+pub mod ledger;
 mod parser;
+pub mod stats;
 
-use eyre::Report;
-use std::env;
-use std::fs;
-
-pub fn day1_part1() {
-    let file_path = "../day1/input.txt";
-    let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    match parser::parse_whole_file(&contents) {
-        Ok((_, mut elves)) => {
-            elves.sort();
-            elves.reverse();
-            println!("Most calorific elf: {:?}", elves.iter().max().unwrap());
-            let top3: i64 = elves.iter().take(3).sum();
-            println!("Top 3 elves calories: {:}", top3);
-        }
-        Err(e) => eprintln!("{:?}", e),
-    };
+use aoc_common::{generate::Generator, tool::Tool, Solution};
+use eyre::eyre;
+use ledger::Elf;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("../test_input.txt"));
+
+    /// The top three elves, most calories first
+    type Input = Vec<Elf>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> eyre::Result<Vec<Elf>> {
+        Ok(ledger::top_elves(input.as_bytes(), 3)?)
+    }
+
+    /// Most calorific elf
+    fn part1(&self, elves: &Vec<Elf>) -> eyre::Result<i64> {
+        elves
+            .first()
+            .map(Elf::calories)
+            .ok_or_else(|| eyre!("no elves"))
+    }
+
+    /// Top 3 elves calories
+    fn part2(&self, elves: &Vec<Elf>) -> eyre::Result<i64> {
+        Ok(elves.iter().map(Elf::calories).sum())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![Box::<stats::StatsTool>::default()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n\n";
+
+    #[test]
+    fn test_sample() -> eyre::Result<()> {
+        let elves = Day1.parse(INPUT)?;
+        assert_eq!(24000, Day1.part1(&elves)?);
+        assert_eq!(45000, Day1.part2(&elves)?);
+        Ok(())
+    }
 }
@@ -0,0 +1,354 @@
+//! A summary of a whole ledger, as a table or as JSON. Reading it keeps one
+//! number per elf, so the ledger can still be far bigger than memory.
+
+use std::{fmt::Display, io::BufRead};
+
+use aoc_common::{parse_param, tool::Tool, Param};
+use eyre::{bail, ensure};
+use serde::Serialize;
+
+use crate::ledger::{Ledger, LedgerError};
+
+/// Percentiles of the elves' calories that go in a summary
+pub const PERCENTILES: [u8; 7] = [1, 10, 25, 50, 75, 90, 99];
+
+/// The biggest snack in the ledger, and who had it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LargestItem {
+    pub elf: usize,
+    pub calories: i64,
+}
+
+/// Elves carrying `from..=to` calories between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: i64,
+    pub to: i64,
+    pub elves: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Percentile {
+    pub percent: u8,
+    pub calories: i64,
+}
+
+/// Everything about how many calories the elves carry. The averages are
+/// `None` for a ledger with no elves in it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Summary {
+    pub elves: usize,
+    pub items: usize,
+    pub calories: i64,
+    /// Indexes of the elves with nothing on them
+    pub empty_elves: Vec<usize>,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    /// Nearest rank, so always what some elf actually carries
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    pub largest_item: Option<LargestItem>,
+}
+
+/// The smallest total at least `percent` of `sorted` is no bigger than
+fn nearest_rank(sorted: &[i64], percent: u8) -> i64 {
+    let rank = (percent as usize * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn median(sorted: &[i64]) -> Option<f64> {
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[mid] as f64),
+        _ => Some((sorted[mid - 1] as f64 + sorted[mid] as f64) / 2.0),
+    }
+}
+
+/// Up to `buckets` buckets of the same width from the smallest total to the
+/// biggest. Fewer when rounding the width up covers the lot sooner.
+fn histogram(sorted: &[i64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    let buckets = buckets.max(1) as i64;
+    let width = (max - min + buckets) / buckets;
+    let mut histogram: Vec<Bucket> = Vec::new();
+    let mut from = min;
+    while from <= max {
+        let to = from + width - 1;
+        let elves = sorted.partition_point(|&c| c <= to) - sorted.partition_point(|&c| c < from);
+        histogram.push(Bucket { from, to, elves });
+        from += width;
+    }
+    histogram
+}
+
+impl Summary {
+    /// Reads the whole of `reader`, sorting elves' calories into `buckets`
+    pub fn from_ledger(reader: impl BufRead, buckets: usize) -> Result<Self, LedgerError> {
+        let mut totals = Vec::new();
+        let mut items = 0;
+        let mut empty_elves = Vec::new();
+        let mut largest_item: Option<LargestItem> = None;
+        for elf in Ledger::new(reader) {
+            let elf = elf?;
+            if elf.items.is_empty() {
+                empty_elves.push(elf.index);
+            }
+            items += elf.items.len();
+            if let Some(&calories) = elf.items.iter().max() {
+                if largest_item.is_none_or(|l| l.calories < calories) {
+                    largest_item = Some(LargestItem {
+                        elf: elf.index,
+                        calories,
+                    });
+                }
+            }
+            totals.push(elf.calories());
+        }
+        totals.sort_unstable();
+
+        let calories = totals.iter().sum();
+        let percentiles = match totals.is_empty() {
+            true => Vec::new(),
+            false => PERCENTILES
+                .iter()
+                .map(|&percent| Percentile {
+                    percent,
+                    calories: nearest_rank(&totals, percent),
+                })
+                .collect(),
+        };
+        Ok(Self {
+            elves: totals.len(),
+            items,
+            calories,
+            empty_elves,
+            mean: (!totals.is_empty()).then(|| calories as f64 / totals.len() as f64),
+            median: median(&totals),
+            percentiles,
+            histogram: histogram(&totals, buckets),
+            largest_item,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("a summary to always make JSON")
+    }
+}
+
+/// ```text
+/// Elves                     5
+/// Items                    10
+/// ...
+/// Percentile         Calories
+/// 1                      4000
+/// ...
+///                  Calories     Elves
+///        4000 -       10666         3  ########################################
+/// ```
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let average = |x: Option<f64>| x.map_or("-".to_string(), |x| format!("{x:.1}"));
+        writeln!(f, "{:<14} {:>12}", "Elves", self.elves)?;
+        writeln!(f, "{:<14} {:>12}", "Items", self.items)?;
+        writeln!(f, "{:<14} {:>12}", "Empty elves", self.empty_elves.len())?;
+        writeln!(f, "{:<14} {:>12}", "Calories", self.calories)?;
+        writeln!(f, "{:<14} {:>12}", "Mean", average(self.mean))?;
+        writeln!(f, "{:<14} {:>12}", "Median", average(self.median))?;
+        if let Some(LargestItem { elf, calories }) = self.largest_item {
+            writeln!(f, "{:<14} {calories:>12}  (elf {elf})", "Largest item")?;
+        }
+        if !self.percentiles.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:<14} {:>12}", "Percentile", "Calories")?;
+            for p in &self.percentiles {
+                writeln!(f, "{:<14} {:>12}", p.percent, p.calories)?;
+            }
+        }
+        if !self.histogram.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:>25}  {:>8}", "Calories", "Elves")?;
+            let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+            for b in &self.histogram {
+                let bar = "#".repeat((b.elves * 40).div_ceil(most.max(1)));
+                writeln!(f, "{:>11} - {:>11}  {:>8}  {bar}", b.from, b.to, b.elves)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `aoc day 1 stats`: a [`Summary`] of the input, as a table or as JSON
+pub(crate) struct StatsTool {
+    buckets: usize,
+    json: bool,
+}
+
+impl Default for StatsTool {
+    fn default() -> Self {
+        Self {
+            buckets: 10,
+            json: false,
+        }
+    }
+}
+
+impl Tool for StatsTool {
+    fn name(&self) -> &'static str {
+        "stats"
+    }
+
+    fn about(&self) -> &'static str {
+        "Summarize the calorie ledger"
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("buckets", "most buckets in the histogram", self.buckets),
+            Param::new("json", "print JSON instead of a table", self.json),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "buckets" => {
+                let buckets = parse_param(name, value)?;
+                ensure!(buckets > 0, "the histogram needs at least one bucket");
+                self.buckets = buckets;
+            }
+            "json" => self.json = parse_param(name, value)?,
+            _ => bail!("stats has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn run(&self, input: &mut dyn BufRead) -> eyre::Result<String> {
+        let summary = Summary::from_ledger(input, self.buckets)?;
+        Ok(match self.json {
+            true => summary.to_json() + "\n",
+            false => summary.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn test_summary() -> Result<(), LedgerError> {
+        let summary = Summary::from_ledger(INPUT.as_bytes(), 3)?;
+        assert_eq!(5, summary.elves);
+        assert_eq!(10, summary.items);
+        assert_eq!(55000, summary.calories);
+        assert_eq!(Some(11000.0), summary.mean);
+        assert_eq!(Some(10000.0), summary.median);
+        assert_eq!(
+            Some(LargestItem {
+                elf: 4,
+                calories: 10000
+            }),
+            summary.largest_item
+        );
+        // 4000, 6000, 10000, 11000 and 24000
+        let percentile = |percent| {
+            summary
+                .percentiles
+                .iter()
+                .find(|p| p.percent == percent)
+                .map(|p| p.calories)
+        };
+        assert_eq!(Some(4000), percentile(1));
+        assert_eq!(Some(6000), percentile(25));
+        assert_eq!(Some(10000), percentile(50));
+        assert_eq!(Some(24000), percentile(99));
+        assert_eq!(
+            vec![
+                Bucket {
+                    from: 4000,
+                    to: 10666,
+                    elves: 3
+                },
+                Bucket {
+                    from: 10667,
+                    to: 17333,
+                    elves: 1
+                },
+                Bucket {
+                    from: 17334,
+                    to: 24000,
+                    elves: 1
+                },
+            ],
+            summary.histogram
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_elves() -> Result<(), LedgerError> {
+        let summary = Summary::from_ledger("5\n\n\n\n7\n3\n".as_bytes(), 10)?;
+        assert_eq!(vec![1, 2], summary.empty_elves);
+        assert_eq!(Some(2.5), summary.median);
+        assert_eq!(Some(0), summary.percentiles.first().map(|p| p.calories));
+        // 0 to 10 is 11 calories, which takes 2 calorie buckets to fit in 10
+        assert_eq!(6, summary.histogram.len());
+        assert_eq!(
+            Bucket {
+                from: 10,
+                to: 11,
+                elves: 1
+            },
+            summary.histogram[5]
+        );
+        assert_eq!(4, summary.histogram.iter().map(|b| b.elves).sum::<usize>());
+
+        let nothing = Summary::from_ledger("".as_bytes(), 10)?;
+        assert_eq!(
+            (0, None, None),
+            (nothing.elves, nothing.mean, nothing.largest_item)
+        );
+        assert!(nothing.histogram.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rendered() -> Result<(), LedgerError> {
+        let summary = Summary::from_ledger(INPUT.as_bytes(), 3)?;
+        let text = summary.to_string();
+        assert!(text.contains("Median              10000.0"), "{text}");
+        assert!(
+            text.contains("Largest item          10000  (elf 4)"),
+            "{text}"
+        );
+        assert!(
+            text.contains("      10667 -       17333         1  ##############\n"),
+            "{text}"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+        assert_eq!(5, json["elves"]);
+        assert_eq!(11000.0, json["mean"]);
+        assert_eq!(4, json["largest_item"]["elf"]);
+        assert_eq!(3, json["histogram"][0]["elves"]);
+        assert_eq!(serde_json::Value::Null, {
+            let nothing = Summary::from_ledger("".as_bytes(), 3)?;
+            serde_json::from_str::<serde_json::Value>(&nothing.to_json()).unwrap()["median"].clone()
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_tool() -> eyre::Result<()> {
+        let mut tool = StatsTool::default();
+        assert!(tool.run(&mut INPUT.as_bytes())?.starts_with("Elves"));
+        tool.set_param("json", "true")?;
+        assert!(tool.run(&mut INPUT.as_bytes())?.contains(r#""elves": 5"#));
+        assert!(tool.set_param("buckets", "0").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+/// A program of `noop`s and `addx`es that keeps X roughly on the screen
+pub(crate) struct InputGenerator {
+    instructions: usize,
+    amount: i64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            instructions: 146,
+            amount: 20,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("instructions", "how many instructions", self.instructions),
+            Param::new("amount", "most one addx adds or takes away", self.amount),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "instructions" => self.instructions = parse_param(name, value)?,
+            "amount" => self.amount = parse_param(name, value)?,
+            _ => bail!("day 10's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.instructions > 0, "the program can't be empty");
+        ensure!(self.amount > 0, "addx has to add something");
+        let mut x = 1;
+        Ok((0..self.instructions)
+            .map(|_| {
+                if rng.chance(1, 3) {
+                    return "noop\n".to_string();
+                }
+                // drift back towards the screen rather than wander off it
+                let add = match rng.range(-self.amount..=self.amount) {
+                    0 => 1,
+                    add if (x + add < 0) || (x + add > 39) => -add,
+                    add => add,
+                };
+                x += add;
+                format!("addx {add}\n")
+            })
+            .collect())
+    }
+}
@@ -1,9 +1,12 @@
 use std::fmt::Display;
 
+use aoc_common::{generate::Generator, Solution};
+
+mod generate;
 mod parser;
 
-#[derive(Debug, PartialEq, Eq)]
-enum Instruction {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
     Noop,
     AddX(i64),
 }
@@ -26,6 +29,7 @@ struct Machine {
     current_instruction: usize,
     pipeline_length: usize,
     signals: Vec<i64>,
+    screen: String,
 }
 
 impl Display for Machine {
@@ -51,15 +55,7 @@ impl Machine {
             current_instruction: 0,
             pipeline_length: cycle_cost,
             signals: Vec::new(),
-        }
-    }
-
-    fn write(&self) {
-        let x = (self.clock % 40) as i64;
-        let c = if x.abs_diff(self.reg_x) < 2 { "#" } else { "." };
-        print!("{}", c);
-        if self.clock % 40 == 0 {
-            println!();
+            screen: String::new(),
         }
     }
 
@@ -74,6 +70,7 @@ impl Machine {
         if (self.clock > 20 && (self.clock - 20) % 40 == 0) || self.clock == 20 {
             self.signals.push(self.reg_x * self.clock as i64);
         }
+        self.draw();
         // op still processing
         if self.pipeline_length > 0 {
             return;
@@ -91,6 +88,16 @@ impl Machine {
         }
     }
 
+    /// The CRT draws one pixel per cycle, lit when the sprite covers it
+    fn draw(&mut self) {
+        let col = ((self.clock - 1) % 40) as i64;
+        let c = if col.abs_diff(self.reg_x) < 2 { '#' } else { '.' };
+        self.screen.push(c);
+        if col == 39 {
+            self.screen.push('\n');
+        }
+    }
+
     fn is_finished(&self) -> bool {
         self.current_instruction >= self.instructions.len()
     }
@@ -100,23 +107,40 @@ impl Machine {
     }
 }
 
-fn main() -> color_eyre::Result<()> {
-    color_eyre::install()?;
-
-    // let instructions = parser::parse_instructions(INPUT);
-    let instructions = parser::parse_instructions(include_str!("input.txt"));
-    let mut machine = Machine::new(1, instructions);
+fn run(instructions: &[Instruction]) -> Machine {
+    let mut machine = Machine::new(1, instructions.to_vec());
     while !machine.is_finished() {
         machine.tick();
-        machine.write();
-        // if machine.clock % 20 == 0 {
-        //     println!("Clock: {}, signal: {:?}", machine.clock, machine.signals);
-        // }
+    }
+    machine
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(INPUT);
+
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Vec<Instruction>> {
+        Ok(parser::parse_instructions(input)?)
     }
 
-    println!("Signal sum: {}", machine.signal_sum());
+    fn part1(&self, instructions: &Vec<Instruction>) -> color_eyre::Result<i64> {
+        Ok(run(instructions).signal_sum())
+    }
+
+    fn part2(&self, instructions: &Vec<Instruction>) -> color_eyre::Result<String> {
+        Ok(run(instructions).screen)
+    }
 
-    Ok(())
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
 }
 
 #[allow(dead_code)]
@@ -291,11 +315,26 @@ mod tests {
 
     #[test]
     fn test_full() {
-        let instructions = parser::parse_instructions(INPUT);
+        let instructions = parser::parse_instructions(INPUT).unwrap();
         let mut machine = Machine::new(1, instructions);
         while !machine.is_finished() {
             machine.tick();
         }
         assert_eq!(13140, machine.signal_sum());
     }
+
+    #[test]
+    fn test_screen() -> color_eyre::Result<()> {
+        let instructions = Day10.parse(INPUT)?;
+        let expected = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+        assert_eq!(expected, Day10.part2(&instructions)?);
+        Ok(())
+    }
 }
@@ -1,11 +1,6 @@
 use crate::Instruction;
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    combinator::{all_consuming, map},
-    sequence::preceded,
-    Finish, IResult,
-};
+use aoc_common::parse::{parse_lines, tag, IResult, ParseError};
+use nom::{branch::alt, combinator::map, sequence::preceded};
 
 fn parse_noop(i: &str) -> IResult<&str, Instruction> {
     map(tag("noop"), |_| Instruction::Noop)(i)
@@ -21,12 +16,8 @@ fn parse_line(i: &str) -> IResult<&str, Instruction> {
     alt((parse_noop, parse_addx))(i)
 }
 
-pub(crate) fn parse_instructions(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map_while(|line| all_consuming(parse_line)(line).finish().ok())
-        .map(|(_, i)| i)
-        .collect()
+pub(crate) fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, parse_line)
 }
 
 #[cfg(test)]
@@ -40,4 +31,11 @@ mod tests {
         assert_eq!(Instruction::AddX(15), x?.1);
         Ok(())
     }
+
+    #[test]
+    fn test_bad_line() {
+        let err = parse_instructions("noop\naddx 3\naddx -\nnoop\n").unwrap_err();
+        assert_eq!((3, 6), (err.line, err.column));
+        assert_eq!("expected a number", err.expected);
+    }
 }
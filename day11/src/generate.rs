@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+use crate::{write_input, Day11, Monkey, Operand, Operation};
+
+/// A different prime for each monkey to test by, like the puzzle's
+const PRIMES: [i64; 14] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43];
+
+/// Monkeys that throw to two others each, never to themselves. Part 1
+/// doesn't keep worry in check, so monkeys are re-rolled until 20 rounds
+/// with the usual relief fit in an `i64`.
+pub(crate) struct InputGenerator {
+    monkeys: usize,
+    items: usize,
+    worry: i64,
+    squares: usize,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            monkeys: 8,
+            items: 8,
+            worry: 99,
+            squares: 1,
+        }
+    }
+}
+
+/// Whether part 1 with the default rounds and relief gets through without
+/// overflowing
+fn fits(monkeys: &[Monkey]) -> bool {
+    let day = Day11::default();
+    let mut items: Vec<VecDeque<i64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    for _ in 0..day.part1_rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            while let Some(old) = items[i].pop_front() {
+                let worry = match &monkey.operation {
+                    Operation::Add(op) => old.checked_add(op.num(old)),
+                    Operation::Multiply(op) => old.checked_mul(op.num(old)),
+                };
+                let Some(worry) = worry.map(|w| w / day.relief) else {
+                    return false;
+                };
+                let dest = match worry % monkey.test_divisor {
+                    0 => monkey.true_monkey,
+                    _ => monkey.false_monkey,
+                };
+                items[dest].push_back(worry);
+            }
+        }
+    }
+    true
+}
+
+impl InputGenerator {
+    fn monkeys(&self, rng: &mut Rng) -> Vec<Monkey> {
+        let mut divisors = PRIMES[..self.monkeys].to_vec();
+        rng.shuffle(&mut divisors);
+        let mut squares = vec![false; self.monkeys];
+        squares[..self.squares].fill(true);
+        rng.shuffle(&mut squares);
+        (0..self.monkeys)
+            .map(|i| {
+                let operation = match (squares[i], rng.chance(1, 2)) {
+                    (true, _) => Operation::Multiply(Operand::Old),
+                    (false, true) => Operation::Multiply(Operand::Num(rng.range(2..=19))),
+                    (false, false) => Operation::Add(Operand::Num(rng.range(1..=8))),
+                };
+                let true_monkey = (i + 1 + rng.index(self.monkeys - 1)) % self.monkeys;
+                let false_monkey = loop {
+                    let other = (i + 1 + rng.index(self.monkeys - 1)) % self.monkeys;
+                    if other != true_monkey {
+                        break other;
+                    }
+                };
+                Monkey {
+                    index: i as u32,
+                    items: (0..=rng.index(self.items))
+                        .map(|_| rng.range(1..=self.worry))
+                        .collect(),
+                    operation,
+                    test_divisor: divisors[i],
+                    true_monkey,
+                    false_monkey,
+                    inspections: 0,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("monkeys", "how many monkeys", self.monkeys),
+            Param::new("items", "most items a monkey starts with", self.items),
+            Param::new("worry", "most worry an item starts with", self.worry),
+            Param::new("squares", "how many monkeys square worry", self.squares),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "monkeys" => self.monkeys = parse_param(name, value)?,
+            "items" => self.items = parse_param(name, value)?,
+            "worry" => self.worry = parse_param(name, value)?,
+            "squares" => self.squares = parse_param(name, value)?,
+            _ => bail!("day 11's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(
+            (3..=PRIMES.len()).contains(&self.monkeys),
+            "there can be 3 to {} monkeys",
+            PRIMES.len()
+        );
+        ensure!(self.items > 0, "every monkey starts with an item");
+        ensure!(self.worry > 0, "worry starts at 1 or more");
+        ensure!(
+            self.squares <= self.monkeys,
+            "there aren't that many monkeys"
+        );
+        // part 2 keeps worry below the product of the divisors, then
+        // multiplies it by at most that product or 19
+        let product: i64 = PRIMES[..self.monkeys].iter().product();
+        let most = if self.squares > 0 { product } else { 19 };
+        ensure!(
+            product.checked_mul(most).is_some(),
+            "part 2 would overflow, try fewer monkeys or squares"
+        );
+        for _ in 0..1000 {
+            let monkeys = self.monkeys(rng);
+            if fits(&monkeys) {
+                return Ok(write_input(&monkeys));
+            }
+        }
+        bail!("every set of monkeys overflowed part 1, try less worry")
+    }
+}
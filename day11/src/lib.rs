@@ -0,0 +1,538 @@
+use std::{cell::RefCell, collections::VecDeque, fmt::Display};
+
+use aoc_common::{differential::Differential, generate::Generator, parse_param, Param, Solution};
+use aoc_cycle::find_cycle;
+use color_eyre::eyre::bail;
+use itertools::Itertools;
+
+mod generate;
+mod parser;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Monkey {
+    index: u32,
+    items: VecDeque<i64>,
+    operation: Operation,
+    test_divisor: i64,
+    true_monkey: usize,
+    false_monkey: usize,
+    inspections: usize,
+}
+
+/// The monkey's note as the puzzle writes it, without the line ending or the
+/// blank line after it
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Monkey {}:", self.index)?;
+        writeln!(f, "  Starting items: {}", self.items.iter().join(", "))?;
+        writeln!(f, "  Operation: {}", self.operation)?;
+        writeln!(f, "  Test: divisible by {}", self.test_divisor)?;
+        writeln!(f, "    If true: throw to monkey {}", self.true_monkey)?;
+        write!(f, "    If false: throw to monkey {}", self.false_monkey)
+    }
+}
+
+impl Monkey {
+    fn receive(&mut self, value: i64) {
+        self.items.push_back(value);
+    }
+}
+
+/// Monkeys that can't play keep-away
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MonkeyError {
+    Operator(char),
+    /// Every test is "divisible by" something, and it can't be 0
+    ZeroDivisor,
+    /// `monkey` throws to `target`, but there are only `monkeys` of them
+    NoSuchMonkey {
+        monkey: u32,
+        target: usize,
+        monkeys: usize,
+    },
+    /// Monkey business needs the two most active monkeys
+    TooFewMonkeys(usize),
+    /// A monkey can't catch what it throws
+    ThrowsToItself(u32),
+}
+
+impl Display for MonkeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MonkeyError::Operator(c) => write!(f, "monkeys only know * and +, not {c:?}"),
+            MonkeyError::ZeroDivisor => write!(f, "nothing is divisible by 0"),
+            MonkeyError::NoSuchMonkey {
+                monkey,
+                target,
+                monkeys,
+            } => write!(
+                f,
+                "monkey {monkey} throws to monkey {target}, but there are only {monkeys} monkeys"
+            ),
+            MonkeyError::TooFewMonkeys(n) => {
+                write!(f, "monkey business needs two monkeys, there are {n}")
+            }
+            MonkeyError::ThrowsToItself(monkey) => {
+                write!(f, "monkey {monkey} throws to itself")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MonkeyError {}
+
+/// Everything about the monkeys that the parser can't check one monkey at a
+/// time
+fn check_monkeys(monkeys: &[Monkey]) -> Result<(), MonkeyError> {
+    if monkeys.len() < 2 {
+        return Err(MonkeyError::TooFewMonkeys(monkeys.len()));
+    }
+    for (position, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.true_monkey, monkey.false_monkey] {
+            if target >= monkeys.len() {
+                return Err(MonkeyError::NoSuchMonkey {
+                    monkey: monkey.index,
+                    target,
+                    monkeys: monkeys.len(),
+                });
+            }
+            if target == position {
+                return Err(MonkeyError::ThrowsToItself(monkey.index));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operand {
+    Num(i64),
+    Old,
+}
+
+impl Operand {
+    fn num(&self, old: i64) -> i64 {
+        match self {
+            Operand::Num(n) => *n,
+            Operand::Old => old,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operation {
+    Add(Operand),
+    // Subtract(Operand),
+    Multiply(Operand),
+    // Divide(Operand),
+}
+
+impl TryFrom<(char, Operand)> for Operation {
+    type Error = MonkeyError;
+
+    fn try_from((value, operand): (char, Operand)) -> Result<Self, MonkeyError> {
+        use Operation::*;
+        match value {
+            '*' => Ok(Multiply(operand)),
+            '+' => Ok(Add(operand)),
+            // '/' => Divide(operand),
+            // '-' => Subtract(operand),
+            _ => Err(MonkeyError::Operator(value)),
+        }
+    }
+}
+
+impl Operation {
+    fn apply(&self, old: i64) -> i64 {
+        match self {
+            Operation::Add(op) => old + op.num(old),
+            Operation::Multiply(op) => old * op.num(old),
+        }
+    }
+}
+
+/// `new = old * 19`
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (c, operand) = match self {
+            Operation::Add(operand) => ('+', operand),
+            Operation::Multiply(operand) => ('*', operand),
+        };
+        match operand {
+            Operand::Num(n) => write!(f, "new = old {c} {n}"),
+            Operand::Old => write!(f, "new = old {c} old"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct BusinessMachine {
+    monkeys: Vec<RefCell<Monkey>>,
+    divisor_product: i64,
+    relief: i64,
+}
+
+impl Display for BusinessMachine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for monkey in &self.monkeys {
+            let monkey = monkey.borrow();
+            writeln!(
+                f,
+                "Monkey {}: {:?} ({})",
+                monkey.index, monkey.items, monkey.inspections
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl BusinessMachine {
+    /// `relief` is what worry levels get divided by after each inspection
+    fn new(monkeys: Vec<Monkey>, relief: i64) -> Self {
+        let divisor_product = monkeys.iter().map(|m| m.test_divisor).product();
+        let monkeys = monkeys.into_iter().map(RefCell::new).collect_vec();
+        Self {
+            monkeys,
+            divisor_product,
+            relief,
+        }
+    }
+    fn round(&mut self) {
+        for monkey_cell in &self.monkeys {
+            let mut monkey = monkey_cell.borrow_mut();
+            loop {
+                // inspect
+                let mut item = match monkey.items.pop_front() {
+                    Some(x) => x,
+                    None => break,
+                };
+                monkey.inspections += 1;
+                // keeping it in the divisor ring only works without relief
+                if self.relief == 1 {
+                    item %= self.divisor_product;
+                }
+                // worry up
+                item = monkey.operation.apply(item);
+                item /= self.relief;
+                // test
+                let dest_monkey = if item % monkey.test_divisor == 0 {
+                    monkey.true_monkey
+                } else {
+                    monkey.false_monkey
+                };
+                // throw
+                self.monkeys[dest_monkey].borrow_mut().receive(item);
+            }
+        }
+    }
+
+    fn monkey_business(&self) -> usize {
+        self.monkeys
+            .iter()
+            .map(|m| m.borrow().inspections)
+            .sorted()
+            .rev()
+            .take(2)
+            .collect_tuple()
+            .map(|(a, b)| a * b)
+            .unwrap()
+    }
+}
+
+/// Where one item is at the start of a round. Without relief, worry can stay
+/// in the divisor ring and items never affect each other, so each one can be
+/// followed on its own until its path repeats.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Item {
+    monkey: usize,
+    worry: i64,
+}
+
+/// One round as far as `item` is concerned: it keeps getting inspected until
+/// it's thrown to a monkey that has already had its turn. A monkey throwing
+/// to itself could keep at it forever, so that ends the round too.
+fn item_round(monkeys: &[Monkey], divisor_product: i64, item: &mut Item, inspections: &mut [u64]) {
+    loop {
+        let monkey = &monkeys[item.monkey];
+        inspections[item.monkey] += 1;
+        item.worry = monkey.operation.apply(item.worry) % divisor_product;
+        let dest = if item.worry % monkey.test_divisor == 0 {
+            monkey.true_monkey
+        } else {
+            monkey.false_monkey
+        };
+        let done = dest <= item.monkey;
+        item.monkey = dest;
+        if done {
+            return;
+        }
+    }
+}
+
+/// Monkey business without relief, extrapolating each item's inspections
+/// once it starts going round in circles
+fn monkey_business_without_relief(monkeys: &[Monkey], rounds: usize) -> u64 {
+    let divisor_product = monkeys.iter().map(|m| m.test_divisor).product();
+    let mut totals = vec![0; monkeys.len()];
+    for (monkey, worry) in monkeys
+        .iter()
+        .enumerate()
+        .flat_map(|(i, m)| m.items.iter().map(move |&w| (i, w)))
+    {
+        let mut state = (
+            Item {
+                monkey,
+                worry: worry % divisor_product,
+            },
+            vec![0; monkeys.len()],
+        );
+        let timeline = find_cycle(
+            &mut state,
+            rounds,
+            |(item, inspections)| item_round(monkeys, divisor_product, item, inspections),
+            |(item, _)| item.clone(),
+            |(_, inspections)| inspections.clone(),
+        );
+        let inspections = timeline
+            .at(rounds)
+            .expect("to have played every round or found a cycle");
+        for (total, n) in totals.iter_mut().zip(inspections) {
+            *total += n;
+        }
+    }
+    totals.iter().sorted().rev().take(2).product()
+}
+
+fn monkey_business(monkeys: &[Monkey], relief: i64, rounds: usize) -> usize {
+    let mut machine = BusinessMachine::new(monkeys.to_vec(), relief);
+    for _ in 0..rounds {
+        machine.round();
+    }
+    machine.monkey_business()
+}
+
+fn load_monkeys(input: &str) -> color_eyre::Result<Vec<Monkey>> {
+    let monkeys = parser::parse_monkeys(input)?;
+    check_monkeys(&monkeys)?;
+    Ok(monkeys)
+}
+
+/// Back into the puzzle's format, so that parsing it gets the same monkeys
+pub fn write_input(monkeys: &[Monkey]) -> String {
+    monkeys.iter().map(|m| format!("{m}\n\n")).collect()
+}
+
+pub struct Day11 {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+    pub relief: i64,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            part1_rounds: 20,
+            part2_rounds: 10_000,
+            relief: 3,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Vec<Monkey>> {
+        load_monkeys(input)
+    }
+
+    fn part1(&self, monkeys: &Vec<Monkey>) -> color_eyre::Result<usize> {
+        Ok(monkey_business(monkeys, self.relief, self.part1_rounds))
+    }
+
+    fn part2(&self, monkeys: &Vec<Monkey>) -> color_eyre::Result<u64> {
+        Ok(monkey_business_without_relief(monkeys, self.part2_rounds))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("part1_rounds", "rounds played in part 1", self.part1_rounds),
+            Param::new("part2_rounds", "rounds played in part 2", self.part2_rounds),
+            Param::new(
+                "relief",
+                "what worry is divided by after each inspection in part 1",
+                self.relief,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "part1_rounds" => self.part1_rounds = parse_param(name, value)?,
+            "part2_rounds" => self.part2_rounds = parse_param(name, value)?,
+            "relief" => match parse_param(name, value)? {
+                relief if relief < 1 => bail!("worry can only be divided by 1 or more"),
+                relief => self.relief = relief,
+            },
+            _ => bail!("day 11 has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+
+    /// Part 2 by playing every round with all the items together
+    fn differentials(&self) -> Vec<Differential> {
+        let rounds = self.part2_rounds;
+        vec![Differential::new(
+            "part 2",
+            move |input| Ok(monkey_business(&load_monkeys(input)?, 1, rounds)),
+            move |input| {
+                Ok(monkey_business_without_relief(
+                    &load_monkeys(input)?,
+                    rounds,
+                ))
+            },
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_sample_input() -> color_eyre::Result<()> {
+        let monkeys = parser::parse_monkeys(include_str!("sample.txt"))?;
+        let mut machine = BusinessMachine::new(monkeys, 1);
+        for _ in 0..10_000 {
+            machine.round();
+        }
+        assert_eq!(2713310158, machine.monkey_business());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_part1() -> color_eyre::Result<()> {
+        let monkeys = Day11::default().parse(include_str!("sample.txt"))?;
+        assert_eq!(10605, Day11::default().part1(&monkeys)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_params() -> color_eyre::Result<()> {
+        let mut day = Day11::default();
+        let monkeys = day.parse(include_str!("sample.txt"))?;
+        day.set_param("part2_rounds", "20")?;
+        // round 20 of the worked example: 99, 97, 8 and 103 inspections
+        assert_eq!(99 * 103, day.part2(&monkeys)?);
+        assert!(day.set_param("rounds", "20").is_err());
+        assert!(day.set_param("relief", "0").is_err());
+        assert!(day.set_param("relief", "-3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        for input in [include_str!("sample.txt"), Day11::INPUT] {
+            let monkeys = parser::parse_monkeys(input)?;
+            let text = write_input(&monkeys);
+            assert_eq!(input, text);
+            assert_eq!(monkeys, parser::parse_monkeys(&text)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_monkeys() -> color_eyre::Result<()> {
+        let monkeys = parser::parse_monkeys(include_str!("sample.txt"))?;
+        assert_eq!(
+            Err(MonkeyError::TooFewMonkeys(1)),
+            check_monkeys(&monkeys[..1])
+        );
+        let err = Day11::default()
+            .parse(&include_str!("sample.txt").replace("throw to monkey 3", "throw to monkey 4"))
+            .unwrap_err();
+        assert_eq!(
+            "monkey 0 throws to monkey 4, but there are only 4 monkeys",
+            err.to_string()
+        );
+        assert_eq!(
+            Err(MonkeyError::ThrowsToItself(0)),
+            check_monkeys(&parser::parse_monkeys(SELF_THROWER)?)
+        );
+        assert!(matches!(
+            Operation::try_from(('/', Operand::Old)),
+            Err(MonkeyError::Operator('/'))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_items_on_their_own() -> color_eyre::Result<()> {
+        let monkeys = parser::parse_monkeys(include_str!("sample.txt"))?;
+        let mut machine = BusinessMachine::new(monkeys.clone(), 1);
+        for rounds in 0..=1000 {
+            if rounds > 1 {
+                let expected = machine.monkey_business() as u64;
+                assert_eq!(expected, monkey_business_without_relief(&monkeys, rounds));
+            }
+            machine.round();
+        }
+        Ok(())
+    }
+
+    /// Monkey 0 keeps everything it gets, so it sees its own item every round
+    /// and monkey 1's from the second round on
+    const SELF_THROWER: &str = "\
+Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+
+    #[test]
+    fn test_throws_to_itself() -> color_eyre::Result<()> {
+        let monkeys = parser::parse_monkeys(SELF_THROWER)?;
+        let mut item = Item {
+            monkey: 0,
+            worry: 79,
+        };
+        let mut inspections = vec![0; 2];
+        item_round(&monkeys, 23 * 19, &mut item, &mut inspections);
+        assert_eq!(0, item.monkey);
+        assert_eq!(vec![1, 0], inspections);
+        assert_eq!(19_999, monkey_business_without_relief(&monkeys, 10_000));
+        Ok(())
+    }
+
+    #[test]
+    fn test_differentials() -> color_eyre::Result<()> {
+        let day = Day11 {
+            part2_rounds: 1000,
+            ..Day11::default()
+        };
+        for diff in day.differentials() {
+            let mut generator = day.generator().unwrap();
+            diff.configure(generator.as_mut())?;
+            assert!(diff.find_mismatch(generator.as_ref(), 0..20)?.is_none());
+        }
+        Ok(())
+    }
+}
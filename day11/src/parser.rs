@@ -7,17 +7,16 @@
 
 use std::collections::VecDeque;
 
+use aoc_common::parse::{parse_many, tag, IResult, ParseError};
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::{anychar, newline},
-    combinator::map,
-    multi::{many1, separated_list1},
+    character::complete::{newline, one_of},
+    combinator::{cut, map, map_res, opt},
+    multi::separated_list1,
     sequence::{delimited, preceded, separated_pair, terminated},
-    Finish, IResult,
 };
 
-use crate::{Monkey, Operand, Operation};
+use crate::{Monkey, MonkeyError, Operand, Operation};
 
 fn monkey_id(i: &str) -> IResult<&str, u32> {
     terminated(
@@ -31,7 +30,7 @@ fn starting_items(i: &str) -> IResult<&str, VecDeque<i64>> {
         preceded(
             tag("  Starting items: "),
             map(
-                separated_list1(tag(", "), nom::character::complete::i64),
+                separated_list1(tag(", "), cut(nom::character::complete::i64)),
                 VecDeque::from,
             ),
         ),
@@ -51,9 +50,10 @@ fn operation(i: &str) -> IResult<&str, Operation> {
     terminated(
         preceded(
             tag("  Operation: new = old "),
-            map(separated_pair(anychar, tag(" "), operand), |(c, op)| {
-                Operation::from(c, op)
-            }),
+            map_res(
+                separated_pair(one_of("*+"), tag(" "), operand),
+                Operation::try_from,
+            ),
         ),
         newline,
     )(i)
@@ -62,7 +62,13 @@ fn operation(i: &str) -> IResult<&str, Operation> {
 fn parse_test(i: &str) -> IResult<&str, i64> {
     //   Test: divisible by 23
     terminated(
-        preceded(tag("  Test: divisible by "), nom::character::complete::i64),
+        preceded(
+            tag("  Test: divisible by "),
+            map_res(nom::character::complete::i64, |n| match n {
+                0 => Err(MonkeyError::ZeroDivisor),
+                n => Ok(n),
+            }),
+        ),
         newline,
     )(i)
 }
@@ -110,10 +116,9 @@ fn parse_monkey(i: &str) -> IResult<&str, Monkey> {
     Ok((i, monkey))
 }
 
-pub(crate) fn parse_monkeys(i: &str) -> color_eyre::Result<Vec<Monkey>> {
-    let x = (many1(terminated(parse_monkey, newline))(i)).finish();
-    let y = x.ok().unwrap();
-    Ok(y.1)
+/// Monkeys are separated by a blank line, which the last one can do without
+pub(crate) fn parse_monkeys(i: &str) -> Result<Vec<Monkey>, ParseError> {
+    parse_many(i, terminated(parse_monkey, opt(newline)))
 }
 
 #[cfg(test)]
@@ -166,4 +171,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bad_monkey() {
+        let input = include_str!("sample.txt").replace("old * old", "old * x");
+        let err = parse_monkeys(&input).unwrap_err();
+        assert_eq!((17, 26), (err.line, err.column));
+        assert_eq!(r#"expected "old" or a number"#, err.expected);
+
+        let input = include_str!("sample.txt").replace("old * old", "old / 2");
+        let err = parse_monkeys(&input).unwrap_err();
+        assert_eq!((17, 24), (err.line, err.column));
+
+        let input = include_str!("sample.txt").replace("divisible by 13", "divisible by 0");
+        let err = parse_monkeys(&input).unwrap_err();
+        assert_eq!((18, 22), (err.line, err.column));
+        assert_eq!("nothing is divisible by 0", err.expected);
+    }
 }
@@ -0,0 +1,110 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+/// A hill climbing steadily from S in the top left to E in the bottom
+/// right. Squares off one random staircase path are dug down by up to
+/// `noise`, so that path is always there but not the only one.
+pub(crate) struct InputGenerator {
+    rows: usize,
+    cols: usize,
+    noise: u8,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            rows: 41,
+            cols: 162,
+            noise: 3,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("rows", "how many rows", self.rows),
+            Param::new("cols", "how many columns", self.cols),
+            Param::new("noise", "most a square is dug down", self.noise),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "rows" => self.rows = parse_param(name, value)?,
+            "cols" => self.cols = parse_param(name, value)?,
+            "noise" => self.noise = parse_param(name, value)?,
+            _ => bail!("day 12's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        let (rows, cols) = (self.rows, self.cols);
+        ensure!(rows > 0 && cols > 0, "the map can't be empty");
+        // so the hill never rises by more than one a step
+        let steps = rows + cols - 2;
+        ensure!(
+            steps >= 25,
+            "it takes 25 steps to climb from a to z, rows + cols has to be at least 27"
+        );
+        let mut on_path = vec![vec![false; cols]; rows];
+        let (mut r, mut c) = (0, 0);
+        on_path[0][0] = true;
+        while (r, c) != (rows - 1, cols - 1) {
+            let down = c == cols - 1 || (r < rows - 1 && rng.chance(rows as u64, steps as u64));
+            if down {
+                r += 1;
+            } else {
+                c += 1;
+            }
+            on_path[r][c] = true;
+        }
+
+        let mut out = String::with_capacity(rows * (cols + 1));
+        for (r, row) in on_path.iter().enumerate() {
+            for (c, &on_path) in row.iter().enumerate() {
+                let height = ((r + c) * 25 / steps) as u8;
+                let height = match on_path {
+                    true => height,
+                    false => height.saturating_sub(rng.below(self.noise as u64 + 1) as u8),
+                };
+                out.push(match (r, c) {
+                    (0, 0) => 'S',
+                    _ if (r, c) == (rows - 1, cols - 1) => 'E',
+                    _ => (b'a' + height) as char,
+                });
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day12;
+
+    #[test]
+    fn test_generated() -> color_eyre::Result<()> {
+        let generator = InputGenerator {
+            rows: 10,
+            cols: 30,
+            noise: 4,
+        };
+        for seed in 0..5 {
+            let input = generator.generate(&mut Rng::new(seed))?;
+            let map = Day12.parse(&input)?;
+            // the staircase path is as short as any path can be
+            assert_eq!(38, Day12.part1(&map)?);
+            assert!(Day12.part2(&map)? <= 37);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+mod generate;
+mod parser;
+
+use std::fmt::Display;
+
+use aoc_common::{generate::Generator, Solution};
+use aoc_grid::{FromChar, Grid, Pos};
+use pathfinding::prelude::dijkstra;
+
+#[derive(Debug)]
+pub struct Map(Grid<MapItem>);
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Map {
+    fn start(&self) -> Option<Pos> {
+        self.0.find(&MapItem::Start)
+    }
+
+    fn end(&self) -> Option<Pos> {
+        self.0.find(&MapItem::End)
+    }
+
+    fn the_a_points(&self) -> Vec<Pos> {
+        self.0.find_all(&MapItem::Ground('a')).collect()
+    }
+
+    fn neighbors(&self, pos: Pos) -> impl Iterator<Item = (Pos, usize)> + '_ {
+        let current = self.0[pos];
+        self.0
+            .neighbours4(pos)
+            .filter(move |&p| current.can_step(&self.0[p]))
+            .map(|p| (p, 1))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapItem {
+    Start,
+    End,
+    Ground(char),
+}
+impl Display for MapItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            MapItem::Start => 'S',
+            MapItem::End => 'E',
+            MapItem::Ground(c) => *c,
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl MapItem {
+    fn can_step(&self, other: &MapItem) -> bool {
+        use MapItem::*;
+        match self {
+            Start => true,
+            End => true,
+            Ground(c) => match other {
+                Start => false,
+                End => *c == 'y' || *c == 'z',
+                Ground(d) => (*c as i32 + 1) >= (*d as i32),
+            },
+        }
+    }
+}
+
+/// Why there's no way up the hill
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapError {
+    Square(char),
+    NoStart,
+    NoEnd,
+    /// Nowhere that was tried as a start can reach the end
+    NoPath,
+}
+
+impl Display for MapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapError::Square(c) => write!(f, "{c:?} isn't S, E or a height from a to z"),
+            MapError::NoStart => write!(f, "the map has no start"),
+            MapError::NoEnd => write!(f, "the map has no end"),
+            MapError::NoPath => write!(f, "there's no way to the end"),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+impl TryFrom<char> for MapItem {
+    type Error = MapError;
+
+    fn try_from(c: char) -> Result<Self, MapError> {
+        use MapItem::*;
+        match c {
+            'S' => Ok(Start),
+            'E' => Ok(End),
+            'a'..='z' => Ok(Ground(c)),
+            _ => Err(MapError::Square(c)),
+        }
+    }
+}
+
+impl FromChar for MapItem {
+    fn from_char(c: char) -> Option<Self> {
+        MapItem::try_from(c).ok()
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Map> {
+        parser::parse_grid(input)
+    }
+
+    fn part1(&self, map: &Map) -> color_eyre::Result<usize> {
+        let start = map.start().ok_or(MapError::NoStart)?;
+        Ok(shortest_path(map, start)?.ok_or(MapError::NoPath)?)
+    }
+
+    fn part2(&self, map: &Map) -> color_eyre::Result<usize> {
+        Ok(scenic_path(map)?)
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+/// `None` when the end can't be reached from `start`
+fn shortest_path(map: &Map, start: Pos) -> Result<Option<usize>, MapError> {
+    let end = map.end().ok_or(MapError::NoEnd)?;
+    let result = dijkstra(&start, |&n| map.neighbors(n), |&n| n == end);
+    Ok(result.map(|x| x.1))
+}
+
+fn scenic_path(map: &Map) -> Result<usize, MapError> {
+    let paths: Vec<_> = map
+        .the_a_points()
+        .into_iter()
+        .map(|start| shortest_path(map, start))
+        .collect::<Result<_, _>>()?;
+    paths.into_iter().flatten().min().ok_or(MapError::NoPath)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_weight() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let grid = parser::parse_grid(input)?;
+        let start = grid.start().unwrap();
+        let weight = shortest_path(&grid, start)?;
+        assert_eq!(Some(31), weight);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scenic() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let grid = parser::parse_grid(input)?;
+        let weight = scenic_path(&grid)?;
+        assert_eq!(29, weight);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_way_up() -> color_eyre::Result<()> {
+        let cliff = parser::parse_grid("Sbz\naaE\n")?;
+        let start = cliff.start().unwrap();
+        assert_eq!(Ok(None), shortest_path(&cliff, start));
+        assert_eq!(Err(MapError::NoPath), scenic_path(&cliff));
+        let no_end = parser::parse_grid("Sab\n")?;
+        assert_eq!(
+            "the map has no end",
+            Day12.part1(&no_end).unwrap_err().to_string()
+        );
+        assert_eq!(Err(MapError::Square('!')), MapItem::try_from('!'));
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        assert_eq!(input, parser::parse_grid(input)?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_can_step() {
+        assert!(MapItem::Ground('c').can_step(&MapItem::Ground('d')));
+        assert!(MapItem::Ground('y').can_step(&MapItem::End));
+        assert!(MapItem::Ground('z').can_step(&MapItem::End));
+        assert!(!MapItem::Ground('c').can_step(&MapItem::Ground('r')));
+        assert!(!MapItem::Ground('c').can_step(&MapItem::Ground('t')));
+        assert!(!MapItem::Ground('c').can_step(&MapItem::End));
+    }
+}
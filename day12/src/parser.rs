@@ -1,26 +1,19 @@
-use crate::{Map, MapItem};
-use grid::Grid;
-use nom::{
-    character::complete::one_of,
-    combinator::{all_consuming, map},
-    multi::many1,
-    Finish, IResult,
-};
+use aoc_grid::Grid;
 
-fn parse_char(i: &str) -> IResult<&str, MapItem> {
-    map(one_of("abcdefghijklmnopqrstuvwxyzSE"), Into::into)(i)
-}
+use crate::Map;
 
-fn parse_line(i: &str) -> IResult<&str, Vec<MapItem>> {
-    many1(parse_char)(i)
+pub(crate) fn parse_grid(input: &str) -> color_eyre::Result<Map> {
+    Ok(Map(Grid::parse(input)?))
 }
 
-pub(crate) fn parse_grid(input: &str) -> Map {
-    let v: Vec<_> = input
-        .lines()
-        .map_while(|line| all_consuming(parse_line)(line).finish().ok())
-        .map(|(_, line)| line)
-        .collect();
-    let cols = v.first().unwrap().len();
-    Map(Grid::from_vec(v.into_iter().flatten().collect(), cols))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_line_is_an_error() {
+        // used to stop at the first bad line and solve what came before it
+        let err = parse_grid("Sab\nab!\nabE\n").unwrap_err();
+        assert_eq!("line 2, column 3: '!' can't be on the map", err.to_string());
+    }
 }
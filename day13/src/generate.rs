@@ -0,0 +1,79 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+/// Pairs of packets, lists of numbers and more lists nested at random
+pub(crate) struct InputGenerator {
+    pairs: usize,
+    depth: usize,
+    width: usize,
+    value: i64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            pairs: 150,
+            depth: 4,
+            width: 5,
+            value: 10,
+        }
+    }
+}
+
+impl InputGenerator {
+    fn packet(&self, rng: &mut Rng, depth: usize, out: &mut String) {
+        out.push('[');
+        for i in 0..rng.index(self.width + 1) {
+            if i > 0 {
+                out.push(',');
+            }
+            if depth < self.depth && rng.chance(1, 3) {
+                self.packet(rng, depth + 1, out);
+            } else {
+                out.push_str(&rng.range(0..=self.value).to_string());
+            }
+        }
+        out.push(']');
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("pairs", "how many pairs of packets", self.pairs),
+            Param::new("depth", "deepest lists nest", self.depth),
+            Param::new("width", "most items in one list", self.width),
+            Param::new("value", "biggest number in a packet", self.value),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "pairs" => self.pairs = parse_param(name, value)?,
+            "depth" => self.depth = parse_param(name, value)?,
+            "width" => self.width = parse_param(name, value)?,
+            "value" => self.value = parse_param(name, value)?,
+            _ => bail!("day 13's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.pairs > 0, "there has to be at least one pair");
+        ensure!(self.value >= 0, "packets only hold whole numbers");
+        let pairs: Vec<String> = (0..self.pairs)
+            .map(|_| {
+                let mut pair = String::new();
+                for _ in 0..2 {
+                    self.packet(rng, 0, &mut pair);
+                    pair.push('\n');
+                }
+                pair
+            })
+            .collect();
+        Ok(pairs.join("\n"))
+    }
+}
@@ -1,13 +1,15 @@
 #![allow(unused)]
 
+mod generate;
+mod parser;
+
 use std::{cmp::Ordering, collections::VecDeque, fmt::Debug};
 
+use aoc_common::{generate::Generator, Solution};
 use itertools::Itertools;
-use serde::Deserialize;
 
-#[derive(PartialEq, Eq, Clone, Deserialize)]
-#[serde(untagged)]
-enum Node {
+#[derive(PartialEq, Eq, Clone)]
+pub enum Node {
     Collection(Vec<Node>),
     Value(i64),
 }
@@ -54,45 +56,71 @@ impl std::cmp::Ord for Node {
     }
 }
 
-fn part1() {
-    let input = include_str!("input.txt");
-    let sum: usize = input
-        .split("\n\n")
+fn ordered_pairs_sum(pairs: &[(Node, Node)]) -> usize {
+    pairs
+        .iter()
         .enumerate()
-        .map(|(i, groups)| {
-            let (l, r) = groups
-                .lines()
-                .map(|line| serde_json::from_str::<Node>(line).unwrap())
-                .collect_tuple()
-                .unwrap();
-            if l < r {
-                i + 1
-            } else {
-                0
-            }
-        })
-        .sum();
-    println!("Sum is {sum}");
+        .filter(|(_, (l, r))| l < r)
+        .map(|(i, _)| i + 1)
+        .sum()
 }
 
-fn main() {
-    let input = include_str!("input.txt");
-    let dividers = vec![
+fn decoder_key(pairs: &[(Node, Node)]) -> usize {
+    let dividers = [
         Node::Collection(vec![Node::Value(2)]),
         Node::Collection(vec![Node::Value(6)]),
     ];
 
-    let mut packets = input
-        .lines()
-        .filter(|s| !s.is_empty())
-        .map(|line| serde_json::from_str::<Node>(line).unwrap())
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(l, r)| [l.clone(), r.clone()])
         .chain(dividers.iter().cloned())
         .collect_vec();
     packets.sort();
 
-    let decoder_key: usize = dividers
+    dividers
         .iter()
         .map(|d| packets.binary_search(d).unwrap() + 1)
-        .product();
-    dbg!(decoder_key);
+        .product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+
+    type Input = Vec<(Node, Node)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parser::pairs(input)?)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> color_eyre::Result<usize> {
+        Ok(ordered_pairs_sum(pairs))
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> color_eyre::Result<usize> {
+        Ok(decoder_key(pairs))
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let pairs = Day13.parse(include_str!("sample.txt"))?;
+        assert_eq!(13, Day13.part1(&pairs)?);
+        assert_eq!(140, Day13.part2(&pairs)?);
+        Ok(())
+    }
 }
@@ -1,81 +1,92 @@
-use color_eyre::eyre::eyre;
-use itertools::Itertools;
-use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::character::complete::{self as cc, newline};
-use nom::combinator::{all_consuming, map};
-use nom::multi::{separated_list0, separated_list1};
-use nom::sequence::{preceded, separated_pair, terminated};
-use nom::Finish;
-use nom_locate::LocatedSpan;
+use aoc_common::parse::{parse_many, tag, ParseError};
+use nom::{
+    branch::alt,
+    character::complete::{self as cc, newline},
+    combinator::{cut, eof, map, opt, recognize},
+    multi::many0,
+    sequence::{delimited, pair, preceded, separated_pair, terminated},
+};
 
-type Span<'a> = LocatedSpan<&'a str>;
-type IResult<'a, T> = nom::IResult<Span<'a>, T>;
+use crate::Node;
 
-use crate::{Item, Pair};
+type Span<'a> = nom_locate::LocatedSpan<&'a str>;
+type IResult<'a, T> = aoc_common::parse::IResult<Span<'a>, T>;
 
-fn item_num(i: Span) -> IResult<Item> {
-    map(cc::i64, Item::Value)(i)
+fn node(i: Span) -> IResult<Node> {
+    alt((map(cc::i64, Node::Value), list))(i)
 }
 
-fn item(i: Span) -> IResult<Item> {
-    alt((item_list, item_num))(i)
-}
-
-fn item_list(i: Span) -> IResult<Item> {
+/// Past a comma there has to be another node, so a bad one is reported as
+/// it is rather than as a missing `]`
+fn list(i: Span) -> IResult<Node> {
     map(
-        terminated(
-            preceded(tag("["), separated_list0(tag(","), item)),
+        delimited(
+            tag("["),
+            opt(pair(node, many0(preceded(tag(","), cut(node))))),
             tag("]"),
         ),
-        Item::from,
+        |nodes| {
+            let Some((first, rest)) = nodes else {
+                return Node::Collection(vec![]);
+            };
+            Node::Collection([first].into_iter().chain(rest).collect())
+        },
     )(i)
 }
 
-fn parse_line(line: &str) -> Option<Item> {
-    all_consuming(item_list)(line.into())
-        .finish()
-        .map(|(_, items)| items)
-        .ok()
+/// A blank line before the next pair, or the end of the input with or
+/// without a last newline
+fn end_of_pair(i: Span) -> IResult<Span> {
+    alt((
+        recognize(pair(newline, newline)),
+        terminated(recognize(opt(newline)), eof),
+    ))(i)
 }
 
-fn parse_pair(i: Span) -> IResult<Pair> {
-    terminated(
-        map(separated_pair(item_list, newline, item_list), Pair::from),
-        newline,
-    )(i)
+fn packet_pair(i: Span) -> IResult<(Node, Node)> {
+    terminated(separated_pair(list, newline, list), end_of_pair)(i)
 }
 
-pub(crate) fn parse_input(input: &str) -> color_eyre::Result<Vec<Pair>> {
-    all_consuming(separated_list1(newline, parse_pair))(input.into())
-        .finish()
-        .map_err(|e| eyre!("{:?}", e))
-        .map(|(_, p)| p)
-        
-
-    // let pairs: Vec<_> = input.lines().map_while(parse_line).collect();
-    // pairs
-    //     .into_iter()
-    //     .collect_tuple()
-    //     .map(|(a, b)| Pair::from((a, b)))
+pub(crate) fn pairs(input: &str) -> Result<Vec<(Node, Node)>, ParseError> {
+    parse_many(input, packet_pair)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use color_eyre::eyre::eyre;
-    use nom::Finish;
-    use test_case::test_case;
-    use Item::*;
+    use Node::*;
 
-    #[test_case("[1,2,3]", vec![1,2,3].into())]
-    #[test_case("[]", Collection(vec![].into()))]
-    fn test_list_of_items(input: &str, items: Item) -> color_eyre::Result<()> {
-        let (_, actual) = item_list(input.into()).finish().map_err(|e| {
-            eprintln!("{:?}", e);
-            eyre!("{:?}", e)
-        })?;
-        assert_eq!(items, actual);
+    #[test]
+    fn test_list() {
+        let (_, nested) = list("[1,[2,[]],3]".into()).unwrap();
+        assert_eq!(
+            Collection(vec![
+                Value(1),
+                Collection(vec![Value(2), Collection(vec![])]),
+                Value(3)
+            ]),
+            nested
+        );
+    }
+
+    #[test]
+    fn test_pairs() -> Result<(), ParseError> {
+        let expected = vec![
+            (Collection(vec![Value(1)]), Collection(vec![])),
+            (Collection(vec![]), Collection(vec![Value(2)])),
+        ];
+        assert_eq!(expected, pairs("[1]\n[]\n\n[]\n[2]\n")?);
+        assert_eq!(expected, pairs("[1]\n[]\n\n[]\n[2]")?);
         Ok(())
     }
+
+    #[test]
+    fn test_bad_input() {
+        let err = pairs("[1,2]\n[3]\n\n[1,2]\n[1,x]\n").unwrap_err();
+        assert_eq!((5, 4), (err.line, err.column));
+
+        // a third packet where the blank line should be
+        let err = pairs("[1]\n[2]\n[3]\n").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+    }
 }
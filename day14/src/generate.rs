@@ -0,0 +1,94 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+use crate::{write_input, Point, Scan};
+
+/// Rock paths turning corners at random, spread out either side of the
+/// sand's source at 500,0 and never on it
+pub(crate) struct InputGenerator {
+    paths: usize,
+    corners: usize,
+    length: i64,
+    width: i64,
+    depth: i64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            paths: 150,
+            corners: 5,
+            length: 10,
+            width: 60,
+            depth: 170,
+        }
+    }
+}
+
+impl InputGenerator {
+    fn scan(&self, rng: &mut Rng) -> Scan {
+        let (left, right) = (500 - self.width, 500 + self.width);
+        let mut point = Point::new(rng.range(left..=right), rng.range(1..=self.depth));
+        let mut points = vec![point];
+        let mut across = rng.chance(1, 2);
+        for _ in 0..=rng.index(self.corners) {
+            let step = rng.range(1..=self.length);
+            // the other way if this way runs off the edge
+            let (x, y) = match across {
+                true if point.x + step <= right => (point.x + step, point.y),
+                true => (point.x - step, point.y),
+                false if point.y + step <= self.depth => (point.x, point.y + step),
+                false => (point.x, point.y - step),
+            };
+            point = Point::new(x, y);
+            points.push(point);
+            across = !across;
+        }
+        Scan(points)
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("paths", "how many rock paths", self.paths),
+            Param::new("corners", "most corners one path turns", self.corners),
+            Param::new("length", "longest a straight bit of rock is", self.length),
+            Param::new(
+                "width",
+                "how far either side of the source rock goes",
+                self.width,
+            ),
+            Param::new("depth", "deepest rock goes", self.depth),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "paths" => self.paths = parse_param(name, value)?,
+            "corners" => self.corners = parse_param(name, value)?,
+            "length" => self.length = parse_param(name, value)?,
+            "width" => self.width = parse_param(name, value)?,
+            "depth" => self.depth = parse_param(name, value)?,
+            _ => bail!("day 14's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.paths > 0, "there has to be at least one path");
+        ensure!(self.length > 0, "rock is at least one step long");
+        // so a step that would run off one edge can go the other way
+        ensure!(
+            self.length <= self.width && 2 * self.length <= self.depth,
+            "the width has to be at least the length, and the depth twice that"
+        );
+        // the part 2 pile spreads as far left as it is deep
+        ensure!(self.depth <= 490, "the cave only goes left as far as x=0");
+        let scans: Vec<Scan> = (0..self.paths).map(|_| self.scan(rng)).collect();
+        Ok(write_input(&scans))
+    }
+}
@@ -0,0 +1,261 @@
+#![allow(unused)]
+mod generate;
+mod parser;
+mod point;
+
+use aoc_common::{generate::Generator, Solution};
+use aoc_geometry::{Bounds, Direction, Vec2};
+use aoc_grid::{Grid, Pos};
+use itertools::{iproduct, Itertools};
+use parser::scans;
+use point::PointPair;
+use std::{default::Default, fmt::Display};
+
+pub(crate) use point::{pos, Point};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Scan(Vec<Point>);
+
+/// `498,4 -> 498,6 -> 496,6`
+impl Display for Scan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut points = self.0.iter().map(|p| format!("{},{}", p.x, p.y));
+        write!(f, "{}", points.join(" -> "))
+    }
+}
+
+/// Back into the puzzle's format, so that parsing it gets the same scans
+pub fn write_input(scans: &[Scan]) -> String {
+    scans.iter().map(|scan| format!("{scan}\n")).collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Spot {
+    Rock,
+    Sand,
+    Air,
+    Start,
+}
+
+impl Display for Spot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Spot::Rock => write!(f, "#"),
+            Spot::Sand => write!(f, "o"),
+            Spot::Air => write!(f, "."),
+            Spot::Start => write!(f, "+"),
+        }
+    }
+}
+
+impl Default for Spot {
+    fn default() -> Self {
+        Spot::Air
+    }
+}
+
+struct Cave {
+    grid: Grid<Spot>,
+    /// Part 2 has a floor two below the lowest rock instead of the abyss
+    floor: bool,
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let first_col = self.first_used_col().expect("Need valid grid");
+
+        write!(f, "    ")?;
+        for col in first_col..self.grid.cols() {
+            if col % 10 == 0 {
+                write!(f, "X")?;
+            } else {
+                write!(f, " ")?;
+            }
+        }
+        writeln!(f)?;
+        for row in 0..self.grid.rows() {
+            write!(f, "{:>3} ", row)?;
+            for col in first_col..self.grid.cols() {
+                write!(f, "{}", self.grid[Pos::new(row, col)])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Cave {
+    fn start() -> Point {
+        Point::new(500, 0)
+    }
+
+    fn new(scans: &[Scan], floor: bool) -> Self {
+        let bounds = Bounds::from_points(scans.iter().flat_map(|s| s.0.iter().copied()))
+            .expect("at least one scan");
+        let max_row = bounds.max.y
+            + 1 // part 2
+            + 1;
+        let max_col = bounds.max.x + 1 + 500;
+        let mut c = Cave {
+            grid: Grid::new(max_row as usize, max_col as usize),
+            floor,
+        };
+        c.set(&Self::start(), Spot::Start);
+
+        c.apply_scans(scans);
+        c
+    }
+
+    fn first_used_col(&self) -> Option<usize> {
+        (0..self.grid.cols()).find(|&col| self.grid.col(col).any(|&spot| spot != Spot::Air))
+    }
+
+    fn set(&mut self, point: &Point, spot: Spot) {
+        self.grid[pos(*point)] = spot;
+    }
+
+    fn apply(&mut self, scan: &Scan) {
+        scan.0 //
+            .windows(2)
+            .map(PointPair::try_from)
+            .for_each(|pp| {
+                pp.unwrap()
+                    .into_iter()
+                    .for_each(|point| self.set(&point, Spot::Rock));
+            });
+    }
+
+    fn apply_scans(&mut self, scans: &[Scan]) {
+        scans.iter().for_each(|s| self.apply(s))
+    }
+
+    fn get(&self, point: Point) -> Option<Spot> {
+        self.grid.get(pos(point)).copied()
+    }
+
+    /// If None, it's slipped off the map
+    fn next_point(&self, point: Point) -> Option<Point> {
+        let below = point + Direction::Down.delta();
+        let below_left = below + Direction::Left.delta();
+        let below_right = below + Direction::Right.delta();
+        if self.get(below).is_none() {
+            // gone off the bottom of the map, or landed on the floor
+            return if self.floor { Some(point) } else { None };
+        }
+        if self.get(below).unwrap() == Spot::Air {
+            return Some(below);
+        }
+        if self.get(below_left).unwrap() == Spot::Air {
+            return Some(below_left);
+        }
+        if self.get(below_right).expect(format!("{point:?}").as_str()) == Spot::Air {
+            return Some(below_right);
+        }
+
+        // can't move, stay still
+        Some(point)
+    }
+
+    /// Returns true when more to go
+    fn drop_sand(&mut self) -> bool {
+        let mut sand_point = Self::start();
+        loop {
+            let next_point = self.next_point(sand_point);
+            if next_point.is_none() {
+                // dropped off
+                return false;
+            }
+            let next_point = next_point.unwrap();
+            if next_point == Self::start() {
+                // part 2: this one plugs the source
+                self.set(&next_point, Spot::Sand);
+                return false;
+            }
+            if next_point == sand_point {
+                // We're not moving any more
+                self.set(&sand_point, Spot::Sand);
+                return true;
+            }
+            sand_point = next_point;
+        }
+    }
+}
+
+fn count_sand(scans: &[Scan], floor: bool) -> usize {
+    let mut cave = Cave::new(scans, floor);
+    let mut sand = 0;
+    while cave.drop_sand() {
+        sand += 1;
+    }
+    if floor {
+        // the grain blocking the source counts too
+        sand + 1
+    } else {
+        sand
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+
+    type Input = Vec<Scan>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Vec<Scan>> {
+        Ok(scans(input)?)
+    }
+
+    fn part1(&self, scans: &Vec<Scan>) -> color_eyre::Result<usize> {
+        Ok(count_sand(scans, false))
+    }
+
+    fn part2(&self, scans: &Vec<Scan>) -> color_eyre::Result<usize> {
+        Ok(count_sand(scans, true))
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let scans = Day14.parse(include_str!("sample.txt"))?;
+        assert_eq!(24, Day14.part1(&scans)?);
+        assert_eq!(93, Day14.part2(&scans)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        for input in [include_str!("sample.txt"), Day14::INPUT] {
+            let scans = Day14.parse(input)?;
+            // sample.txt has no line ending on its last line
+            assert_eq!(input.trim_end(), write_input(&scans).trim_end());
+            assert_eq!(scans, Day14.parse(&write_input(&scans))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> color_eyre::Result<()> {
+        let scans = Day14.parse(include_str!("sample.txt"))?;
+        let cave = Cave::new(&scans, false);
+        let drawn = cave.to_string();
+        // the grid runs well off to the right to leave room for part 2
+        let rows: Vec<_> = drawn.lines().skip(1).map(|row| &row[..14]).collect();
+        assert_eq!("  0 ......+...", rows[0]);
+        assert_eq!("  4 ....#...##", rows[4]);
+        assert_eq!("  9 #########.", rows[9]);
+        Ok(())
+    }
+}
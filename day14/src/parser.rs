@@ -1,11 +1,11 @@
-use color_eyre::eyre::eyre;
-use nom::combinator::{all_consuming, map};
+use aoc_common::parse::{parse_lines, tag, ParseError};
+use nom::character::complete as cc;
+use nom::combinator::{cut, map};
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
-use nom::{bytes::complete::tag, character::complete as cc};
 use nom_locate::LocatedSpan;
 
-type IResult<'a, T> = nom::IResult<Span<'a>, T>;
+type IResult<'a, T> = aoc_common::parse::IResult<Span<'a>, T>;
 type Span<'a> = LocatedSpan<&'a str>;
 
 use crate::{Point, Scan};
@@ -15,13 +15,13 @@ fn point(i: Span) -> IResult<Point> {
 }
 
 fn line(i: Span) -> IResult<Scan> {
-    map(separated_list1(tag(" -> "), point), |points| Scan(points))(i)
+    map(separated_list1(tag(" -> "), cut(point)), |points| {
+        Scan(points)
+    })(i)
 }
 
-pub(crate) fn scan_line(input: &str) -> color_eyre::Result<Scan> {
-    all_consuming(line)(input.into())
-        .map_err(|e| eyre!("{:?}", e))
-        .map(|(_, scan)| scan)
+pub(crate) fn scans(input: &str) -> Result<Vec<Scan>, ParseError> {
+    parse_lines(input, line)
 }
 
 #[cfg(test)]
@@ -31,9 +31,20 @@ mod tests {
     #[test]
     fn test_scan() -> color_eyre::Result<()> {
         let input = "498,4 -> 498,6 -> 496,6";
-        let scan = scan_line(input)?;
-        let expected = Scan(vec![Point(498, 4), Point(498, 6), Point(496, 6)]);
+        let scan = scans(input)?.remove(0);
+        let expected = Scan(vec![
+            Point::new(498, 4),
+            Point::new(498, 6),
+            Point::new(496, 6),
+        ]);
         assert_eq!(expected, scan);
         Ok(())
     }
+
+    #[test]
+    fn test_bad_scan() {
+        let err = scans("498,4 -> 498,6\n503,4 -> 502 4\n").unwrap_err();
+        assert_eq!((2, 13), (err.line, err.column));
+        assert_eq!(r#"expected ",""#, err.expected);
+    }
 }
@@ -0,0 +1,166 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+use crate::{write_input, Point, Sensor, Zone};
+
+/// Sensors covering every spot in `0..=max` but one, the distress beacon.
+///
+/// Every sensor's reach stops one short of the distress beacon. Four of
+/// them sit diagonally around it to close it in, the rest go anywhere,
+/// and then rows are checked top to bottom and another sensor goes in
+/// each gap that's left. Part 1's row is `max / 2`, as with the real
+/// input, and the distress beacon is kept off it.
+pub(crate) struct InputGenerator {
+    sensors: usize,
+    max: i64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            sensors: 30,
+            max: 4_000_000,
+        }
+    }
+}
+
+/// A sensor at `location` reaching as far as it can without finding
+/// `distress`. Its beacon is right next to the distress beacon, where no
+/// other sensor can see it, until [`move_beacons`] finds it somewhere else.
+fn sensor(location: Point, distress: Point) -> Sensor {
+    let towards = location - distress;
+    let step = match towards.x {
+        0 => Point::new(0, towards.y.signum()),
+        x => Point::new(x.signum(), 0),
+    };
+    Sensor::from((location, distress + step))
+}
+
+/// Moves beacons to random spots on the edge of their sensor's reach, where
+/// they can, so long as no other sensor can see them and they stay the
+/// closest beacon to every sensor
+fn move_beacons(rng: &mut Rng, zone: &mut Zone) {
+    for i in 0..zone.sensors.len() {
+        let (location, reach) = (zone.sensors[i].location, zone.sensors[i].exclusion_distance);
+        for _ in 0..20 {
+            let dx = rng.range(-reach..=reach);
+            let dy = (reach - dx.abs()) * if rng.chance(1, 2) { 1 } else { -1 };
+            let beacon = location + Point::new(dx, dy);
+            let hidden = zone
+                .sensors
+                .iter()
+                .all(|s| beacon.manhattan(s.location) >= s.exclusion_distance);
+            if hidden {
+                zone.sensors[i] = Sensor::from((location, beacon));
+                break;
+            }
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "sensors",
+                "sensors placed before filling the gaps",
+                self.sensors,
+            ),
+            Param::new("max", "sensors cover 0..=max on both axes", self.max),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "sensors" => self.sensors = parse_param(name, value)?,
+            "max" => self.max = parse_param(name, value)?,
+            _ => bail!("day 15's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        let max = self.max;
+        ensure!(max >= 4, "max has to be at least 4 to fit the sensors in");
+        let distress = loop {
+            let p = Point::new(rng.range(1..=max - 1), rng.range(1..=max - 1));
+            if p.y != max / 2 {
+                break p;
+            }
+        };
+        let edge = [distress.x, distress.y, max - distress.x, max - distress.y];
+        let near = rng.range(1..=edge.into_iter().min().unwrap());
+        let mut zone = Zone::new(Vec::new());
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let location = distress + Point::new(dx * near, dy * near);
+            zone.sensors.push(sensor(location, distress));
+        }
+        while zone.sensors.len() < self.sensors + 4 {
+            let location = Point::new(rng.range(0..=max), rng.range(0..=max));
+            if location != distress {
+                zone.sensors.push(sensor(location, distress));
+            }
+        }
+
+        let bounds = 0..=max as usize;
+        for row in bounds.clone() {
+            loop {
+                let clear = zone
+                    .ranges_for_row(row, max as usize)
+                    .complement(bounds.clone());
+                let gap = clear
+                    .iter()
+                    .flatten()
+                    .map(|x| Point::new(x as i64, row as i64))
+                    .find(|&p| p != distress);
+                let Some(location) = gap else {
+                    break;
+                };
+                zone.sensors.push(sensor(location, distress));
+            }
+        }
+        move_beacons(rng, &mut zone);
+        Ok(write_input(&zone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+    use itertools::iproduct;
+
+    use super::*;
+    use crate::Day15;
+
+    #[test]
+    fn test_generated() -> color_eyre::Result<()> {
+        let generator = InputGenerator {
+            sensors: 10,
+            max: 60,
+        };
+        let day = Day15 { row: 30, max: 60 };
+        for seed in 0..5 {
+            let input = generator.generate(&mut Rng::new(seed))?;
+            let zone = day.parse(&input)?;
+            assert!(zone.sensors.len() >= 14);
+            let uncovered: Vec<Point> = iproduct!(0..=60, 0..=60)
+                .map(|(x, y)| Point::new(x, y))
+                .filter(|p| !zone.is_clear(p))
+                .collect();
+            assert_eq!(1, uncovered.len());
+            let [distress] = uncovered[..] else {
+                unreachable!()
+            };
+            assert_eq!(distress.x * 4_000_000 + distress.y, day.part2(&zone)?);
+            day.part1(&zone)?;
+            // no sensor can see a beacon closer than its own
+            for (s, b) in iproduct!(&zone.sensors, &zone.sensors) {
+                assert!(b.beacon.manhattan(s.location) >= s.exclusion_distance);
+            }
+        }
+        Ok(())
+    }
+}
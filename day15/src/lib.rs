@@ -0,0 +1,289 @@
+#![allow(unused)]
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+use aoc_common::{differential::Differential, generate::Generator, parse_param, Param, Solution};
+use aoc_geometry::Vec2;
+use aoc_interval::IntervalSet;
+use color_eyre::eyre::{bail, eyre};
+use grid::Grid;
+use itertools::{iproduct, Itertools};
+use sensor::Sensor;
+
+mod generate;
+mod parser;
+mod sensor;
+
+type Point = Vec2<i64>;
+
+fn tuning_frequency(p: Point) -> i64 {
+    p.x * 4000000 + p.y
+}
+
+pub struct Zone {
+    sensors: Vec<Sensor>,
+}
+
+impl Zone {
+    fn new(sensors: Vec<Sensor>) -> Self {
+        Self { sensors }
+    }
+
+    fn is_clear(&self, point: &Point) -> bool {
+        self.sensors
+            .iter()
+            .any(|sensor| point.manhattan(sensor.location) <= sensor.exclusion_distance)
+    }
+
+    fn num_clear_on_row(&self, row: i64) -> color_eyre::Result<usize> {
+        let closest_sensor = self
+            .sensors
+            .iter()
+            .map(|sensor| sensor.location)
+            .min_by(|a, b| {
+                let x = a.x.abs_diff(b.x) as i64;
+                let t = Point::new(x / 2, row);
+                let a_dist = a.manhattan(t);
+                let b_dist = b.manhattan(t);
+                a_dist.cmp(&b_dist)
+            })
+            .ok_or_else(|| eyre!("there are no sensors"))?;
+        let starting_point = Point::new(closest_sensor.x, row);
+        if !self.is_clear(&starting_point) {
+            bail!("no sensor reaches row {row} at x={}", starting_point.x);
+        }
+
+        let right_start = starting_point.x + 1;
+        let right_count = (right_start..)
+            .take_while(|&x| self.is_clear(&Point::new(x, row)))
+            .count();
+        let mut left_index = starting_point.x + 1;
+        loop {
+            if !self.is_clear(&Point::new(left_index, row)) {
+                break;
+            }
+            left_index -= 1;
+        }
+        let left_count = (starting_point.x - left_index) as usize;
+
+        let beacons_on_row = self
+            .sensors
+            .iter()
+            .map(|sensor| sensor.beacon)
+            .unique()
+            .filter(|b| b.y == row)
+            .count();
+
+        let count = left_count + right_count - beacons_on_row;
+
+        Ok(count)
+    }
+
+    fn brute_force_beacon_search(&self, max: usize) -> Option<Point> {
+        iproduct!(0..=max, 0..=max)
+            .into_iter()
+            .map(|(x, y)| Point::new(x as i64, y as i64))
+            .find(|p| !self.is_clear(p))
+    }
+
+    fn ranges_for_row(&self, row: usize, max: usize) -> IntervalSet<usize> {
+        self.sensors
+            .iter()
+            .filter_map(|s| s.range_for_row(row, max))
+            .collect()
+    }
+
+    /// The one spot on `0..=max` that no sensor covers
+    fn tuning_frequency(&self, max: usize) -> color_eyre::Result<i64> {
+        let (x, row) = (0..=max)
+            .find_map(|row| {
+                let clear = self.ranges_for_row(row, max).complement(0..=max);
+                clear.span().map(|x| (*x.start(), row))
+            })
+            .ok_or_else(|| eyre!("the sensors cover all of 0..={max}"))?;
+        let p = Point::new(x as i64, row as i64);
+        // let p = self.brute_force_beacon_search(max).unwrap();
+        Ok(tuning_frequency(p))
+    }
+}
+
+fn load_zone(input: &str) -> color_eyre::Result<Zone> {
+    Ok(Zone::new(parser::sensors(input)?))
+}
+
+/// Back into the puzzle's format, so that parsing it gets the same sensors
+pub fn write_input(zone: &Zone) -> String {
+    zone.sensors.iter().map(|s| format!("{s}\n")).collect()
+}
+
+pub struct Day15 {
+    pub row: i64,
+    pub max: usize,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: 2_000_000,
+            max: 4_000_000,
+        }
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+    const SAMPLE_PARAMS: &'static [(&'static str, &'static str)] = &[("row", "10"), ("max", "20")];
+
+    type Input = Zone;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Zone> {
+        load_zone(input)
+    }
+
+    fn part1(&self, zone: &Zone) -> color_eyre::Result<usize> {
+        zone.num_clear_on_row(self.row)
+    }
+
+    fn part2(&self, zone: &Zone) -> color_eyre::Result<i64> {
+        zone.tuning_frequency(self.max)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("row", "row part 1 counts clear positions on", self.row),
+            Param::new("max", "part 2 searches 0..=max on both axes", self.max),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "row" => self.row = parse_param(name, value)?,
+            "max" => self.max = parse_param(name, value)?,
+            _ => bail!("day 15 has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+
+    /// Part 2 by trying every spot, on areas small enough for that
+    fn differentials(&self) -> Vec<Differential> {
+        const MAX: usize = 30;
+        vec![Differential::new(
+            "part 2",
+            |input| {
+                load_zone(input)?
+                    .brute_force_beacon_search(MAX)
+                    .map(tuning_frequency)
+                    .ok_or_else(|| eyre!("every spot is covered"))
+            },
+            |input| load_zone(input)?.tuning_frequency(MAX),
+        )
+        .with_setting("max", "30")
+        .with_setting("sensors", "6")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_for_sample_11() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let zone = load_zone(input)?;
+        let r = zone.ranges_for_row(11, 20);
+        assert_eq!(vec![(0..=13), (15..=20)], r.iter().collect_vec());
+        Ok(())
+    }
+
+    #[test]
+    fn test_zone_checks_out() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let zone = load_zone(input)?;
+        assert!(zone.is_clear(&Point::new(-2, 10)));
+        assert!(zone.is_clear(&Point::new(2, 10)));
+        assert!(zone.is_clear(&Point::new(24, 10)));
+
+        assert!(!zone.is_clear(&Point::new(-3, 10)));
+        assert!(!zone.is_clear(&Point::new(25, 10)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let zone = load_zone(input)?;
+        assert_eq!(26, zone.num_clear_on_row(10)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_params() -> color_eyre::Result<()> {
+        let day = Day15 { row: 10, max: 20 };
+        let zone = day.parse(include_str!("sample.txt"))?;
+        assert_eq!(26, day.part1(&zone)?);
+        assert_eq!(56_000_011, day.part2(&zone)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        for input in [include_str!("sample.txt"), Day15::INPUT] {
+            let zone = load_zone(input)?;
+            // sample.txt has no line ending on its last line
+            assert_eq!(input.trim_end(), write_input(&zone).trim_end());
+            assert_eq!(zone.sensors, load_zone(&write_input(&zone))?.sensors);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuning_freq() -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let zone = load_zone(input)?;
+        assert_eq!(56_000_011, zone.tuning_frequency(20)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_differentials() -> color_eyre::Result<()> {
+        let zone = load_zone(include_str!("sample.txt"))?;
+        assert_eq!(Some(Point::new(14, 11)), zone.brute_force_beacon_search(20));
+        for diff in Day15::default().differentials() {
+            let mut generator = Day15::default().generator().unwrap();
+            diff.configure(generator.as_mut())?;
+            assert!(diff.find_mismatch(generator.as_ref(), 0..20)?.is_none());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_corner() -> color_eyre::Result<()> {
+        // covers all of 0..=4 but the far corner
+        let zone = load_zone("Sensor at x=0, y=0: closest beacon is at x=7, y=0\n")?;
+        assert_eq!(Some(Point::new(4, 4)), zone.brute_force_beacon_search(4));
+        assert_eq!(16_000_004, zone.tuning_frequency(4)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nothing_to_find() -> color_eyre::Result<()> {
+        let day = Day15 {
+            max: 0,
+            ..Day15::default()
+        };
+        let zone = day.parse(Day15::INPUT)?;
+        assert_eq!(5_508_234, day.part1(&zone)?);
+        let err = day.part2(&zone).unwrap_err();
+        assert_eq!("the sensors cover all of 0..=0", err.to_string());
+        assert!(zone.num_clear_on_row(100_000_000).is_err());
+        Ok(())
+    }
+}
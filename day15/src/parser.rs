@@ -1,15 +1,14 @@
-use color_eyre::eyre::eyre;
-use nom::combinator::{all_consuming, map};
+use aoc_common::parse::{parse_lines, tag, ParseError};
+use nom::character::complete as cc;
+use nom::combinator::map;
 use nom::sequence::{preceded, tuple};
-use nom::Finish;
-use nom::{bytes::complete::tag, character::complete as cc};
 use nom_locate::LocatedSpan;
 
-type IResult<'a, T> = nom::IResult<Span<'a>, T>;
+type IResult<'a, T> = aoc_common::parse::IResult<Span<'a>, T>;
 type Span<'a> = LocatedSpan<&'a str>;
 
+use crate::Point;
 use crate::Sensor;
-use crate::point::Point;
 
 fn sensor(i: Span) -> IResult<Point> {
     // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
@@ -33,10 +32,25 @@ fn beacon(i: Span) -> IResult<Point> {
     )(i)
 }
 
-pub fn line(i: &str) -> color_eyre::Result<Sensor> {
-    all_consuming(tuple((sensor, beacon)))(i.into())
-        .finish()
-        .map_err(|e| eyre!("{:?}", e))
-        .map(|(_, sb)| sb)
-        .map(Into::into)
+fn line(i: Span) -> IResult<Sensor> {
+    map(tuple((sensor, beacon)), Into::into)(i)
+}
+
+pub fn sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    parse_lines(input, line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bad_sensor() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16; closest beacon is at x=10, y=16
+";
+        let err = sensors(input).unwrap_err();
+        assert_eq!((2, 20), (err.line, err.column));
+        assert_eq!(r#"expected ": closest beacon is at x=""#, err.expected);
+    }
 }
@@ -1,17 +1,17 @@
-use std::ops::RangeInclusive;
+use std::{fmt::Display, ops::RangeInclusive};
 
-use crate::{point::Point, MAX};
+use crate::Point;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct Sensor {
     pub location: Point,
     pub beacon: Point,
-    pub exclusion_distance: u64,
+    pub exclusion_distance: i64,
 }
 
 impl From<(Point, Point)> for Sensor {
     fn from((location, beacon): (Point, Point)) -> Self {
-        let exclusion_distance = location.distance(&beacon);
+        let exclusion_distance = location.manhattan(beacon);
         Self {
             location,
             beacon,
@@ -20,18 +20,34 @@ impl From<(Point, Point)> for Sensor {
     }
 }
 
+/// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`
+impl Display for Sensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (s, b) = (self.location, self.beacon);
+        write!(
+            f,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            s.x, s.y, b.x, b.y
+        )
+    }
+}
+
 impl Sensor {
     /// Note: for part 2 so limited to max
-    pub fn range_for_row(&self, row: usize) -> Option<RangeInclusive<usize>> {
-        let y_dist = row.abs_diff(self.location.1 as usize);
+    pub fn range_for_row(&self, row: usize, max: usize) -> Option<RangeInclusive<usize>> {
+        let y_dist = row.abs_diff(self.location.y as usize);
         if y_dist > self.exclusion_distance as usize {
             return None;
         }
-        let x_dist = (self.exclusion_distance as usize - y_dist) ;
-        let start = self.location.0 - x_dist as i64;
+        let x_dist = (self.exclusion_distance as usize - y_dist);
+        let start = self.location.x - x_dist as i64;
         let start = if start < 0 { 0 } else { start } as usize;
-        let end = self.location.0 + x_dist as i64;
-        let end = if end as usize > crate::MAX { crate::MAX } else { end as usize};
+        let end = self.location.x + x_dist as i64;
+        let end = if end as usize > max {
+            max
+        } else {
+            end as usize
+        };
         Some(start..=end)
     }
 }
@@ -47,7 +63,7 @@ mod tests {
     #[test_case(17, None; "Out of range")]
     #[test_case(7, Some(0..=17); "parts below zero cut off")]
     fn test_2_7(row: usize, expected: Option<RangeInclusive<usize>>) {
-        let sensor = Sensor::from((Point(8, 7), Point(2, 10)));
-        assert_eq!(expected, sensor.range_for_row(row));
+        let sensor = Sensor::from((Point::new(8, 7), Point::new(2, 10)));
+        assert_eq!(expected, sensor.range_for_row(row, 20));
     }
 }
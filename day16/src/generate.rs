@@ -0,0 +1,105 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+use itertools::Itertools;
+
+use crate::{
+    maze::{Maze, Valve},
+    write_input,
+};
+
+/// A connected cave of valves with two letter names, starting from a
+/// broken valve AA. The tunnels are a random tree with a few shortcuts
+/// added, and every tunnel goes both ways.
+pub(crate) struct InputGenerator {
+    valves: usize,
+    working: usize,
+    rate: usize,
+    shortcuts: usize,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            valves: 58,
+            working: 15,
+            rate: 25,
+            shortcuts: 10,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("valves", "how many valves", self.valves),
+            Param::new("working", "how many valves have a flow rate", self.working),
+            Param::new("rate", "highest flow rate", self.rate),
+            Param::new("shortcuts", "tunnels on top of the tree", self.shortcuts),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "valves" => self.valves = parse_param(name, value)?,
+            "working" => self.working = parse_param(name, value)?,
+            "rate" => self.rate = parse_param(name, value)?,
+            "shortcuts" => self.shortcuts = parse_param(name, value)?,
+            _ => bail!("day 16's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(
+            (2..=26 * 26).contains(&self.valves),
+            "there can be 2 to {} valves",
+            26 * 26
+        );
+        ensure!(self.working < self.valves, "AA never works");
+        // the search keeps the start and the working valves in a NodeSet
+        ensure!(self.working < 64, "at most 63 valves can work");
+        ensure!(self.rate > 0, "working valves have a flow rate");
+
+        let mut names: Vec<String> = ('A'..='Z')
+            .cartesian_product('A'..='Z')
+            .map(|(a, b)| format!("{a}{b}"))
+            .skip(1)
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(self.valves - 1);
+        names.insert(0, "AA".to_string());
+
+        let mut tunnels = vec![Vec::new(); self.valves];
+        let mut connect = |a: usize, b: usize| {
+            if a != b && !tunnels[a].contains(&b) {
+                tunnels[a].push(b);
+                tunnels[b].push(a);
+            }
+        };
+        for valve in 1..self.valves {
+            connect(valve, rng.index(valve));
+        }
+        for _ in 0..self.shortcuts {
+            connect(rng.index(self.valves), rng.index(self.valves));
+        }
+
+        let mut rates = vec![0; self.valves];
+        for rate in &mut rates[1..=self.working] {
+            *rate = 1 + rng.index(self.rate);
+        }
+        rng.shuffle(&mut rates[1..]);
+
+        let valves = (0..self.valves)
+            .map(|i| {
+                let mut to = tunnels[i].iter().map(|&t| names[t].clone()).collect_vec();
+                rng.shuffle(&mut to);
+                let valve = Valve::new(names[i].clone(), rates[i], to);
+                (names[i].clone(), valve)
+            })
+            .collect();
+        Ok(write_input(&Maze { valves }))
+    }
+}
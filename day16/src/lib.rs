@@ -0,0 +1,90 @@
+#![allow(unused)]
+mod generate;
+mod maze;
+mod parser;
+
+use aoc_common::{generate::Generator, parse_param, Param, Solution};
+use color_eyre::eyre::bail;
+use maze::Maze;
+
+/// Back into the puzzle's format, so that parsing it gets the same valves
+pub fn write_input(maze: &Maze) -> String {
+    maze.to_string()
+}
+
+pub struct Day16 {
+    pub start: String,
+    pub minutes: usize,
+}
+
+impl Default for Day16 {
+    fn default() -> Self {
+        Self {
+            start: "AA".to_string(),
+            minutes: 30,
+        }
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+
+    type Input = Maze;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Maze> {
+        Maze::parse(input)
+    }
+
+    fn part1(&self, maze: &Maze) -> color_eyre::Result<usize> {
+        maze.most_pressure(&self.start, self.minutes)
+    }
+
+    /// Teaching the elephant takes 4 of the minutes
+    fn part2(&self, maze: &Maze) -> color_eyre::Result<usize> {
+        let minutes = self.minutes.saturating_sub(4);
+        maze.most_pressure_with_help(&self.start, minutes)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("start", "valve to start from", &self.start),
+            Param::new("minutes", "minutes before the volcano erupts", self.minutes),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "start" => self.start = value.to_string(),
+            "minutes" => self.minutes = parse_param(name, value)?,
+            _ => bail!("day 16 has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_part_1() -> color_eyre::Result<()> {
+        let maze = Maze::parse(include_str!("sample.txt"))?;
+        assert_eq!(1651, maze.most_pressure("AA", 30)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_part_2() -> color_eyre::Result<()> {
+        let maze = Maze::parse(include_str!("sample.txt"))?;
+        assert_eq!(1707, maze.most_pressure_with_help("AA", 26)?);
+        Ok(())
+    }
+}
@@ -1,18 +1,14 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    hash::Hash,
-};
+use std::{collections::HashMap, fmt::Display, hash::Hash};
 
+use aoc_graph::{CompactGraph, NodeSet};
+use color_eyre::eyre::eyre;
 use itertools::Itertools;
-use pathfinding::prelude::dijkstra;
-use regex::{Match, Regex};
 
 #[derive(Debug, Clone, Eq)]
 pub struct Valve {
     name: String,
     rate: usize,
     tunnels: Vec<String>,
-    open: bool,
 }
 
 impl Hash for Valve {
@@ -20,7 +16,6 @@ impl Hash for Valve {
         self.name.hash(state);
         self.rate.hash(state);
         self.tunnels.hash(state);
-        self.open.hash(state);
     }
 }
 
@@ -31,129 +26,172 @@ impl PartialEq for Valve {
 }
 
 impl Valve {
-    fn new(name: String, rate: usize, tunnels: Vec<String>) -> Self {
+    pub(crate) fn new(name: String, rate: usize, tunnels: Vec<String>) -> Self {
         Self {
             name,
             rate,
             tunnels,
-            open: false,
         }
     }
 }
 
-#[derive(Debug)]
+/// `Valve BB has flow rate=13; tunnels lead to valves CC, AA`, and the
+/// singular wording when there's only the one tunnel
+impl Display for Valve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lead = match self.tunnels.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        write!(
+            f,
+            "Valve {} has flow rate={}; {lead} {}",
+            self.name,
+            self.rate,
+            self.tunnels.join(", ")
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Maze {
     pub valves: HashMap<String, Valve>,
-    pub pressure_released: usize,
-    time_remaining: usize,
-    current: String,
-    path: Option<VecDeque<String>>,
+}
+
+/// One valve a line, in order of name
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for name in self.valves.keys().sorted() {
+            writeln!(f, "{}", self.valves[name])?;
+        }
+        Ok(())
+    }
+}
+
+/// The maze cut down to the start and the valves worth opening
+struct Tunnels {
+    graph: CompactGraph<String>,
+    /// By index in `graph`
+    rates: Vec<usize>,
+    start: usize,
 }
 
 impl Maze {
     pub fn parse(input: &str) -> color_eyre::Result<Maze> {
-        let re = Regex::new(r"Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.+)")
-            .unwrap();
-
-        let mut valves: HashMap<String, Valve> = input
-            .lines()
-            .filter_map(|line| {
-                re.captures(line)
-                    .expect(line)
-                    .iter()
-                    .skip(1)
-                    .flatten()
-                    .collect_tuple::<(_, _, _)>()
-                    .map(|(name, rate, tunnels)| {
-                        let ts = tunnels.as_str().split(", ").map(String::from).collect_vec();
-                        Valve::new(
-                            name.as_str().to_string(),
-                            str::parse::<usize>(rate.as_str()).unwrap(),
-                            ts,
-                        )
-                    })
-            })
+        let valves = crate::parser::valves(input)?
+            .into_iter()
             .map(|v| (v.name.clone(), v))
-            .collect::<HashMap<_, _>>();
-
-        Ok(Maze {
-            valves,
-            pressure_released: 0,
-            current: "AA".to_string(),
-            time_remaining: 30,
-            path: None,
-        })
+            .collect();
+        Ok(Maze { valves })
     }
 
-    fn open_valves_pressure(&self) -> usize {
-        self.valves
+    fn tunnels(&self, start: &str) -> color_eyre::Result<Tunnels> {
+        if !self.valves.contains_key(start) {
+            return Err(eyre!("there's no valve {start}"));
+        }
+        let working = self
+            .valves
             .values()
-            .filter(|v| v.open)
-            .map(|v| v.rate)
-            .sum()
+            .filter(|v| v.rate > 0)
+            .map(|v| v.name.clone())
+            .sorted()
+            .collect_vec();
+        // the start takes a place too when it isn't one of them
+        let nodes = working.len() + usize::from(self.valves[start].rate == 0);
+        if nodes > NodeSet::MAX {
+            return Err(eyre!(
+                "too many working valves: {} counting the start, and at most {} fit",
+                nodes,
+                NodeSet::MAX
+            ));
+        }
+        let graph = CompactGraph::new(std::iter::once(start.to_string()).chain(working), |v| {
+            self.valves[v].tunnels.iter().map(|t| (t.clone(), 1))
+        });
+        let rates = (0..graph.len())
+            .map(|i| self.valves[graph.node(i)].rate)
+            .collect();
+        Ok(Tunnels {
+            graph,
+            rates,
+            start: 0,
+        })
     }
 
-    fn distance_to(&self, dest: &str) -> usize {
-        self.path_to(dest).1
+    /// The most pressure that can be released in `minutes` for every set of
+    /// valves that can be opened in that time
+    fn best_by_opened(
+        &self,
+        start: &str,
+        minutes: usize,
+    ) -> color_eyre::Result<HashMap<NodeSet, usize>> {
+        let tunnels = self.tunnels(start)?;
+        let working = (0..tunnels.graph.len())
+            .filter(|&i| tunnels.rates[i] > 0)
+            .collect();
+        let mut best = HashMap::new();
+        tunnels.visit(
+            tunnels.start,
+            NodeSet::new(),
+            working,
+            minutes,
+            0,
+            &mut best,
+        );
+        Ok(best)
     }
 
-    fn path_to(&self, dest: &str) -> (Vec<String>, usize) {
-        let start = &self.current;
-        let path = dijkstra(
-            start,
-            |valve| {
-                let tunnels = &self.valves.get(valve).unwrap().tunnels;
-                tunnels.iter().map(|t| (t.clone(), 1))
-            },
-            |v| v == dest,
-        );
-        let (p, c) = path.unwrap_or_else(|| panic!("path to {} to exist", dest));
-        // skip first node
-        (p.into_iter().skip(1).collect(), c)
+    pub fn most_pressure(&self, start: &str, minutes: usize) -> color_eyre::Result<usize> {
+        let best = self.best_by_opened(start, minutes)?;
+        Ok(best.into_values().max().unwrap_or(0))
     }
 
-    fn next_valve(&self) -> String {
-        // best valve is most pressure over time = (current time - time to move) * rate
-        // is_open
-        let start = self.valves.get(&self.current).unwrap().clone();
-        let x = self
-            .valves
-            .values() //
-            .filter(|v| !v.open && v.rate > 0)
-            .map(|v| {
-                (
-                    v.name.clone(),
-                    (self.time_remaining - self.distance_to(&v.name)) * v.rate,
-                )
-            })
-            .inspect(|v| println!("{} will release {}", v.0, v.1))
-            .max_by(|a, b| a.1.cmp(&b.1));
-        x.unwrap().0
-    }
-
-    fn open_current(&mut self) {
-        self.valves.get_mut(&self.current).unwrap().open = true;
-    }
-
-    pub fn release_pressure(&mut self) {
-        while self.time_remaining > 0 {
-            self.time_remaining -= 1;
-            self.pressure_released += self.open_valves_pressure();
-            if self.path.is_some() {
-                // we're moving
-                if let Some(next) = self.path.as_mut().unwrap().pop_front() {
-                    println!("Moving to {next}");
-                    self.current = next;
-                } else {
-                    self.path = None; // next cycle find a new path
-                    self.open_current();
-                }
-            } else {
-                let next_valve = self.next_valve();
-                println!("Going to open {next_valve}");
-                let path: VecDeque<_> = VecDeque::from(self.path_to(&next_valve).0);
-                self.path = Some(path);
-            }
+    /// With an elephant opening valves too. The two of them never need to
+    /// open the same valve, so this is the best pair of disjoint sets.
+    pub fn most_pressure_with_help(
+        &self,
+        start: &str,
+        minutes: usize,
+    ) -> color_eyre::Result<usize> {
+        let best = self.best_by_opened(start, minutes)?;
+        let best = best.into_iter().collect_vec();
+        let pairs = best.iter().tuple_combinations();
+        let together = pairs
+            .filter(|((a, _), (b, _))| a.is_disjoint(*b))
+            .map(|((_, x), (_, y))| x + y);
+        let alone = best.iter().map(|(_, x)| *x);
+        Ok(together.chain(alone).max().unwrap_or(0))
+    }
+}
+
+impl Tunnels {
+    fn visit(
+        &self,
+        at: usize,
+        opened: NodeSet,
+        closed: NodeSet,
+        minutes: usize,
+        released: usize,
+        best: &mut HashMap<NodeSet, usize>,
+    ) {
+        let entry = best.entry(opened).or_default();
+        *entry = released.max(*entry);
+        // only the start can still be closed while standing at it
+        let here = closed.contains(at).then_some((at, 0));
+        for (next, distance) in here.into_iter().chain(self.graph.reachable(at, closed)) {
+            // walking there, then a minute to open it
+            let Some(left) = minutes.checked_sub(distance + 1) else {
+                continue;
+            };
+            let released = released + left * self.rates[next];
+            self.visit(
+                next,
+                opened.with(next),
+                closed.without(next),
+                left,
+                released,
+                best,
+            );
         }
     }
 }
@@ -165,18 +203,74 @@ mod tests {
 
     #[test]
     fn test_path() -> color_eyre::Result<()> {
-        let mut maze = Maze::parse(include_str!("sample.txt"))?;
+        let maze = Maze::parse(include_str!("sample.txt"))?;
+        let tunnels = maze.tunnels("AA")?;
+        let index = |v: &str| tunnels.graph.index_of(&v.to_string()).unwrap();
 
-        assert_eq!((vec!["DD".to_string()], 1), maze.path_to("DD"));
+        assert_eq!(Some(1), tunnels.graph.distance(0, index("DD")));
+        assert_eq!(Some(2), tunnels.graph.distance(index("DD"), index("BB")));
+        assert_eq!(Some(3), tunnels.graph.distance(index("HH"), index("EE")));
+        assert_eq!(None, tunnels.graph.index_of(&"FF".to_string()));
+        assert_eq!(vec![0, 13, 2, 20, 3, 22, 21], tunnels.rates);
+        Ok(())
+    }
 
-        maze.current = "DD".into();
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        // the sample happens to list its valves in order of name
+        let input = include_str!("sample.txt");
+        let maze = Maze::parse(input)?;
+        assert_eq!(input.trim_end(), maze.to_string().trim_end());
 
+        let maze = Maze::parse(include_str!("input.txt"))?;
+        let text = maze.to_string();
+        let again = Maze::parse(&text)?;
+        assert_eq!(text, again.to_string());
+        for (name, valve) in &maze.valves {
+            let other = &again.valves[name];
+            assert_eq!((valve.rate, &valve.tunnels), (other.rate, &other.tunnels));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_start() -> color_eyre::Result<()> {
+        let maze = Maze::parse(include_str!("sample.txt"))?;
+        assert!(maze.most_pressure("ZZ", 30).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_working_start() -> color_eyre::Result<()> {
+        let maze = Maze::parse("Valve AA has flow rate=10; tunnel leads to valve AA\n")?;
+        assert_eq!(29 * 10, maze.most_pressure("AA", 30)?);
+        assert_eq!(29 * 10, maze.most_pressure_with_help("AA", 30)?);
+
+        // opening BB on the way out comes first when it's worth more
+        let maze = Maze::parse(
+            "Valve AA has flow rate=1; tunnel leads to valve BB\n\
+             Valve BB has flow rate=5; tunnel leads to valve AA\n",
+        )?;
+        assert_eq!(28 * 5 + 26, maze.most_pressure("AA", 30)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_many_valves() -> color_eyre::Result<()> {
+        let names =
+            (0..70).map(|i| format!("{}{}", (b'A' + i / 26) as char, (b'A' + i % 26) as char));
+        let input: String = names
+            .clone()
+            .zip(names.clone().cycle().skip(1))
+            .map(|(name, next)| {
+                format!("Valve {name} has flow rate=1; tunnel leads to valve {next}\n")
+            })
+            .collect();
+        let err = Maze::parse(&input)?.most_pressure("AA", 30).unwrap_err();
         assert_eq!(
-            (vec!["CC".to_string(), "BB".to_string()], 2),
-            maze.path_to("BB")
+            "too many working valves: 70 counting the start, and at most 64 fit",
+            err.to_string()
         );
-
-        // assert_eq!()
         Ok(())
     }
 }
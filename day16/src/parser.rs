@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use aoc_common::parse::{parse_lines, tag, IResult, ParseError};
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, u64},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    Offset,
+};
+
+use crate::maze::Valve;
+
+/// `Valve BB has flow rate=13; tunnels lead to valves CC, AA`, or the
+/// singular wording when there's only the one tunnel. The tunnels stay as
+/// slices of the input so a bad one can be pointed at.
+fn valve(i: &str) -> IResult<&str, (&str, u64, Vec<&str>)> {
+    let lead = alt((
+        tag("; tunnels lead to valves "),
+        tag("; tunnel leads to valve "),
+    ));
+    tuple((
+        preceded(tag("Valve "), alpha1),
+        preceded(tag(" has flow rate="), u64),
+        preceded(lead, separated_list1(tag(", "), alpha1)),
+    ))(i)
+}
+
+/// Every tunnel has to lead to a valve that's in the input
+pub(crate) fn valves(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let valves = parse_lines(input, valve)?;
+    let names: HashSet<&str> = valves.iter().map(|&(name, ..)| name).collect();
+    let tunnels = valves.iter().flat_map(|(_, _, tunnels)| tunnels);
+    if let Some(missing) = tunnels.copied().find(|t| !names.contains(t)) {
+        return Err(ParseError::at(
+            input,
+            input.offset(missing),
+            format!("there's no valve {missing}"),
+        ));
+    }
+    Ok(valves
+        .into_iter()
+        .map(|(name, rate, tunnels)| {
+            let tunnels = tunnels.into_iter().map(String::from).collect();
+            Valve::new(name.to_string(), rate as usize, tunnels)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valve() {
+        let (_, valve) = valve("Valve HH has flow rate=22; tunnel leads to valve GG").unwrap();
+        assert_eq!(("HH", 22, vec!["GG"]), valve);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB\n\
+                     Valve BB has flow rate=x; tunnels lead to valves AA\n";
+        let err = valves(input).unwrap_err();
+        assert_eq!((2, 24), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_missing_tunnel() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves AA, BB\n";
+        let err = valves(input).unwrap_err();
+        assert_eq!((1, 54), (err.line, err.column));
+        assert_eq!("there's no valve BB", err.expected);
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+use crate::{write_input, Jet};
+
+/// A random pattern of jets, pushing left `left` percent of the time
+pub(crate) struct InputGenerator {
+    length: usize,
+    left: u64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            length: 10091,
+            left: 50,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("length", "how many jets before they repeat", self.length),
+            Param::new("left", "percent of jets pushing left", self.left),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "length" => self.length = parse_param(name, value)?,
+            "left" => self.left = parse_param(name, value)?,
+            _ => bail!("day 17's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.length > 0, "there has to be at least one jet");
+        ensure!(self.left <= 100, "left is a percentage");
+        let jets: Vec<Jet> = (0..self.length)
+            .map(|_| match rng.chance(self.left, 100) {
+                true => Jet::Left,
+                false => Jet::Right,
+            })
+            .collect();
+        Ok(write_input(&jets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn test_generated() -> color_eyre::Result<()> {
+        for seed in 0..5 {
+            let generator = InputGenerator {
+                length: 50,
+                left: 25 * seed,
+            };
+            let input = generator.generate(&mut Rng::new(seed))?;
+            let jets = Day17.parse(&input)?;
+            assert_eq!(50, jets.len());
+            // rocks are at most 4 high and at least 4 squares, in a chamber
+            // 7 wide
+            let height = Day17.part1(&jets)?;
+            assert!((2022 * 4 / 7..=2022 * 4).contains(&height));
+            assert!(Day17.part2(&jets)? > height);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,335 @@
+#![allow(unused)]
+
+mod generate;
+mod parser;
+mod rock;
+
+use std::collections::HashSet;
+use std::fmt::{Debug, Display};
+
+use aoc_common::{differential::Differential, generate::Generator, Solution};
+use aoc_cycle::find_cycle;
+use aoc_geometry::Vec2;
+use rock::{Rock, RockIterator};
+
+extern crate derivative;
+use derivative::Derivative;
+
+/// `y` counts up from the floor
+type Pos = Vec2<isize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+impl Jet {
+    fn delta(self) -> Pos {
+        match self {
+            Jet::Left => Pos::new(-1, 0),
+            Jet::Right => Pos::new(1, 0),
+        }
+    }
+}
+
+impl Display for Jet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Jet::Left => "<",
+                Jet::Right => ">",
+            }
+        )
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+struct Chamber {
+    settled: HashSet<Pos>,
+    /// The very highest rock, -1 being the floor
+    top: isize,
+    #[derivative(Debug = "ignore")]
+    rock_iter: RockIterator,
+    rounds: usize,
+    jets: Vec<Jet>,
+    next_jet: usize,
+    /// The most rows under the top that any rock has looked at on its way
+    /// down, counting the one it came to rest on
+    deepest: isize,
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in (0..=self.the_top()).rev() {
+            for col in (0..=6) {
+                if self.settled.contains(&Pos::new(col, row)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f, "")?;
+        }
+        Ok(())
+    }
+}
+
+impl Chamber {
+    fn parse(input: &str) -> color_eyre::Result<Self> {
+        let jets = parser::parse_line(input)?;
+        Ok(Self::new(jets))
+    }
+
+    fn new(jets: Vec<Jet>) -> Self {
+        Self {
+            settled: HashSet::new(),
+            top: -1,
+            rock_iter: RockIterator::new(),
+            rounds: 0,
+            jets,
+            next_jet: 0,
+            deepest: 0,
+        }
+    }
+
+    /// The very highest rock
+    fn the_top(&self) -> isize {
+        self.top
+    }
+
+    /// The open cells a rock could get to from above the pile, going
+    /// sideways and down, as far down as any rock has been. A rock only ever
+    /// looks at cells in here or at settled ones, so nothing else decides
+    /// where it lands.
+    fn open_region(&self) -> HashSet<Pos> {
+        let above = self.top + 1;
+        let bottom = self.top - self.deepest;
+        let mut open: HashSet<Pos> = (0..7).map(|col| Pos::new(col, above)).collect();
+        let mut todo: Vec<Pos> = open.iter().copied().collect();
+        while let Some(pos) = todo.pop() {
+            for delta in [Pos::new(-1, 0), Pos::new(1, 0), Pos::new(0, -1)] {
+                let next = pos + delta;
+                if (0..7).contains(&next.x)
+                    && next.y >= bottom
+                    && self.is_empty(next, false)
+                    && open.insert(next)
+                {
+                    todo.push(next);
+                }
+            }
+        }
+        open
+    }
+
+    /// Everything the next rocks depend on: which rock and jet come next, and
+    /// the open region above the pile, measured down from the top.
+    ///
+    /// Two chambers with the same fingerprint had no rock go deeper than
+    /// `deepest` in between, so the rocks after the second do what the rocks
+    /// after the first did, and never look below what the two share. A
+    /// region going all the way down would be just as sure, but jets that
+    /// never push a rock into some column leave it open to the floor, and
+    /// then the region grows with the pile and never repeats.
+    fn fingerprint(&self) -> (usize, usize, isize, Vec<Pos>) {
+        let mut region: Vec<Pos> = self
+            .open_region()
+            .into_iter()
+            .map(|pos| Pos::new(pos.x, self.top - pos.y))
+            .collect();
+        region.sort();
+        (self.rounds % 5, self.next_jet, self.deepest, region)
+    }
+
+    fn drop(&mut self) {
+        let rock = self.rock_iter.next().unwrap();
+        let mut pos = Pos::new(2, self.the_top() + rock.height() + 3);
+        // println!("New rock {rock:?} at {pos}");
+        let final_pos = loop {
+            let shift = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            let next_pos = {
+                let np = pos + shift.delta();
+                if rock.pos_iter(np).any(|p| p.x >= 7 || p.x < 0) {
+                    pos
+                } else {
+                    np
+                }
+            };
+            // println!(" Gas to {next_pos}");
+            // a rock in the way stops the push, not the fall
+            if !self.collision(rock, next_pos) {
+                pos = next_pos;
+            }
+
+            let next_pos = pos + Pos::new(0, -1); // Rock drops
+                                                  // println!("    Dropped to {next_pos}");
+
+            if self.collision(rock, next_pos) {
+                break pos;
+            } else {
+                pos = next_pos;
+            }
+        };
+        // println!("Placing {rock:?} at {final_pos}");
+        self.settled.extend(rock.pos_iter(final_pos));
+        // it looked at the row under where it stopped
+        let depth = self.top - (final_pos.y - rock.height());
+        self.deepest = self.deepest.max(depth);
+        self.top = self.top.max(final_pos.y);
+        self.rounds += 1;
+    }
+
+    fn is_empty(&self, pos: Pos, debug: bool) -> bool {
+        if pos.y < 0 {
+            return false;
+        }
+        !self.settled.contains(&pos)
+    }
+
+    fn collision(&self, rock: Rock, pos: Pos) -> bool {
+        let d = false;
+        let d = rock == Rock::Box;
+        let is_all_rock_empty = rock
+            .pos_iter(pos) //
+            .all(|rock_pos| self.is_empty(rock_pos, d));
+        if (d) {
+            // println!("{rock:?} at {pos} is empty: {is_all_rock_empty}");
+        }
+        !is_all_rock_empty
+    }
+
+    fn drop_rounds(&mut self, rounds: usize) {
+        for _ in 0..rounds {
+            self.drop();
+            // println!("{self}");
+            // println!();
+        }
+    }
+
+    fn height(&self) -> isize {
+        self.the_top() + 1
+    }
+}
+
+/// Drops rocks until the pile starts repeating itself, then works out the
+/// rest
+fn height_after(jets: &[Jet], rocks: usize) -> isize {
+    let mut chamber = Chamber::new(jets.to_vec());
+    find_cycle(
+        &mut chamber,
+        rocks,
+        Chamber::drop,
+        Chamber::fingerprint,
+        Chamber::height,
+    )
+    .at(rocks)
+    .expect("to have dropped every rock or found a cycle")
+}
+
+/// Back into the puzzle's format, so that parsing it gets the same jets
+pub fn write_input(jets: &[Jet]) -> String {
+    let jets: String = jets.iter().map(Jet::to_string).collect();
+    format!("{jets}\n")
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(include_str!("sample.txt"));
+
+    type Input = Vec<Jet>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Vec<Jet>> {
+        Ok(parser::parse_line(input)?)
+    }
+
+    fn part1(&self, jets: &Vec<Jet>) -> color_eyre::Result<isize> {
+        Ok(height_after(jets, 2022))
+    }
+
+    fn part2(&self, jets: &Vec<Jet>) -> color_eyre::Result<isize> {
+        Ok(height_after(jets, 1_000_000_000_000))
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+
+    /// Part 1 by dropping every rock. Short jet patterns repeat sooner, so
+    /// the cycle skipping gets a workout.
+    fn differentials(&self) -> Vec<Differential> {
+        vec![Differential::new(
+            "part 1",
+            |input| {
+                let mut chamber = Chamber::parse(input)?;
+                chamber.drop_rounds(2022);
+                Ok(chamber.height())
+            },
+            |input| Ok(height_after(&Day17.parse(input)?, 2022)),
+        )
+        .with_setting("length", "40")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        for input in [include_str!("sample.txt"), Day17::INPUT] {
+            let jets = Day17.parse(input)?;
+            assert_eq!(input.trim_end(), write_input(&jets).trim_end());
+            assert_eq!(jets, Day17.parse(&write_input(&jets))?);
+        }
+        Ok(())
+    }
+
+    #[test_case(2, 4)]
+    #[test_case(3, 6)]
+    #[test_case(4, 7)]
+    #[test_case(5, 9)]
+    #[test_case(2022, 3068)]
+    fn test_sample(rounds: usize, height: isize) -> color_eyre::Result<()> {
+        let input = include_str!("sample.txt");
+        let mut chamber = Chamber::parse(input)?;
+        chamber.drop_rounds(rounds);
+        assert_eq!(height, chamber.height());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycle() -> color_eyre::Result<()> {
+        let jets = Day17.parse(include_str!("sample.txt"))?;
+        for rocks in [0, 1, 500, 2022, 3333] {
+            let mut chamber = Chamber::new(jets.clone());
+            chamber.drop_rounds(rocks);
+            assert_eq!(
+                chamber.height(),
+                height_after(&jets, rocks),
+                "{rocks} rocks"
+            );
+        }
+        assert_eq!(1_514_285_714_288, Day17.part2(&jets)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_differentials() -> color_eyre::Result<()> {
+        for diff in Day17.differentials() {
+            let mut generator = Day17.generator().unwrap();
+            diff.configure(generator.as_mut())?;
+            assert!(diff.find_mismatch(generator.as_ref(), 0..100)?.is_none());
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,17 @@
+use aoc_common::parse::{parse_all, ParseError};
 use nom::{
     branch::alt,
     character::complete as ncc,
-    combinator::{all_consuming, map},
-    multi::many1,
-    Finish,
+    combinator::{eof, map, opt, verify},
+    error::context,
+    multi::many_till,
+    sequence::pair,
 };
 
 use crate::Jet;
 
 type Span<'a> = nom_locate::LocatedSpan<&'a str>;
-type IResult<'a, T> = nom::IResult<Span<'a>, T>;
+type IResult<'a, T> = aoc_common::parse::IResult<Span<'a>, T>;
 
 fn left(i: Span) -> IResult<Jet> {
     map(ncc::char('<'), |_| Jet::Left)(i)
@@ -19,14 +21,36 @@ fn right(i: Span) -> IResult<Jet> {
     map(ncc::char('>'), |_| Jet::Right)(i)
 }
 
+/// Not `many1`, which would stop quietly at a character that isn't a jet
 fn line(i: Span) -> IResult<Vec<Jet>> {
-    many1(alt((left, right)))(i)
+    let jets = map(
+        many_till(alt((left, right)), pair(opt(ncc::newline), eof)),
+        |(jets, _)| jets,
+    );
+    context(
+        "at least one jet",
+        verify(jets, |jets: &Vec<Jet>| !jets.is_empty()),
+    )(i)
 }
 
-pub fn parse_line(i: &str) -> color_eyre::Result<Vec<Jet>> {
-    let i = i.strip_suffix('\n').unwrap();
-    all_consuming(line)(i.into())
-        .finish()
-        .map_err(|e| color_eyre::eyre::eyre!("{e:?}"))
-        .map(|(_, v)| v)
+pub fn parse_line(i: &str) -> Result<Vec<Jet>, ParseError> {
+    parse_all(i, line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(Ok(vec![Jet::Left, Jet::Right]), parse_line("<>"));
+        assert_eq!(Ok(vec![Jet::Right]), parse_line(">\n"));
+        let err = parse_line(">><x<\n").unwrap_err();
+        assert_eq!((1, 4), (err.line, err.column));
+        assert_eq!("expected '<' or '>'", err.expected);
+        assert_eq!(
+            "expected at least one jet",
+            parse_line("\n").unwrap_err().expected
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use num_derive::{FromPrimitive, ToPrimitive};
 
-use crate::pos::Pos;
+use crate::Pos;
 
 #[derive(Debug, Eq, PartialEq, FromPrimitive, ToPrimitive, Clone, Copy)]
 pub enum Rock {
@@ -35,7 +35,7 @@ impl Rock {
     }
 
     pub fn pos_iter(&self, pos: Pos) -> impl Iterator<Item = Pos> {
-        self.places_iter().map(move |p| pos + p)
+        self.places_iter().map(move |p| pos + Pos::from(p))
     }
 }
 
@@ -0,0 +1,25 @@
+use aoc_common::Solution;
+use color_eyre::eyre::bail;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input = String;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, _input: &String) -> color_eyre::Result<i64> {
+        bail!("day 19 part 1 is not solved yet")
+    }
+
+    fn part2(&self, _input: &String) -> color_eyre::Result<i64> {
+        bail!("day 19 part 2 is not solved yet")
+    }
+}
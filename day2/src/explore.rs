@@ -0,0 +1,380 @@
+//! What the strategy guide would be worth if the elf meant something else
+//! by the second column. Part 1 reads its letters as my hand and part 2 as
+//! how the round has to end; this scores every way of matching them up to
+//! hands, and every way of matching them up to results.
+
+use std::{cmp::Reverse, collections::HashMap, fmt::Display, io::BufRead};
+
+use aoc_common::tool::Tool;
+
+use crate::{
+    game::{Game, GameError, GameResult, Hand},
+    parser,
+    rules::{RuleSet, RESULTS},
+};
+
+/// What each letter of the second column stands for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Meaning {
+    Hands(Vec<(char, Hand)>),
+    Results(Vec<(char, GameResult)>),
+}
+
+/// One reading of the second column, and the guide's total under it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub meaning: Meaning,
+    pub score: i64,
+}
+
+/// Every reading of the guide. A kind of reading the guide doesn't fit, like
+/// results when a line's letter is only ever a hand, is the error that line
+/// gets from its part.
+#[derive(Debug, Clone)]
+pub struct Exploration {
+    /// The puzzle's own reading first
+    pub hands: Result<Vec<Interpretation>, GameError>,
+    /// The puzzle's own reading first
+    pub results: Result<Vec<Interpretation>, GameError>,
+    rules: RuleSet,
+}
+
+/// Every ordering of `0..n`, smallest first, so the first one leaves
+/// everything where it is
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    let mut all = Vec::new();
+    let mut current: Vec<usize> = (0..n).collect();
+    loop {
+        all.push(current.clone());
+        let Some(i) = (1..n).rev().find(|&i| current[i - 1] < current[i]) else {
+            return all;
+        };
+        let j = (i..n)
+            .rev()
+            .find(|&j| current[j] > current[i - 1])
+            .expect("something after i to be bigger");
+        current.swap(i - 1, j);
+        current[i..].reverse();
+    }
+}
+
+/// How many times each elf hand meets each letter. Scoring a reading only
+/// has to go through these, not the whole guide.
+fn tally(games: &[Game]) -> HashMap<(Hand, char), i64> {
+    let mut counts = HashMap::new();
+    for game in games {
+        *counts
+            .entry((game.elf_hand(), game.response()))
+            .or_default() += 1;
+    }
+    counts
+}
+
+fn score_hands(
+    rules: &RuleSet,
+    counts: &HashMap<(Hand, char), i64>,
+) -> Result<Vec<Interpretation>, GameError> {
+    let symbols = rules.hand_symbols();
+    if let Some(&(_, c)) = counts.keys().find(|(_, c)| !symbols.contains(c)) {
+        return Err(GameError::NotAHand(c));
+    }
+    let hands: Vec<Hand> = rules.hands().collect();
+    Ok(permutations(hands.len())
+        .into_iter()
+        .map(|order| {
+            let mapping: Vec<(char, Hand)> = symbols
+                .iter()
+                .zip(order)
+                .map(|(&c, i)| (c, hands[i]))
+                .collect();
+            let score = counts
+                .iter()
+                .map(|(&(elf, c), &n)| {
+                    let (_, mine) = mapping.iter().find(|(s, _)| *s == c).unwrap();
+                    let result = rules.result(*mine, elf);
+                    n * (rules.hand_points(*mine) + rules.result_points(result))
+                })
+                .sum();
+            Interpretation {
+                meaning: Meaning::Hands(mapping),
+                score,
+            }
+        })
+        .collect())
+}
+
+fn score_results(
+    rules: &RuleSet,
+    counts: &HashMap<(Hand, char), i64>,
+) -> Result<Vec<Interpretation>, GameError> {
+    let symbols = rules.result_symbols();
+    if let Some(&(_, c)) = counts.keys().find(|(_, c)| !symbols.contains(c)) {
+        return Err(GameError::NotAResult(c));
+    }
+    Ok(permutations(RESULTS.len())
+        .into_iter()
+        .map(|order| {
+            let mapping: Vec<(char, GameResult)> = symbols
+                .iter()
+                .zip(order)
+                .map(|(&c, i)| (c, RESULTS[i]))
+                .collect();
+            let score = counts
+                .iter()
+                .map(|(&(elf, c), &n)| {
+                    let (_, result) = mapping.iter().find(|(s, _)| *s == c).unwrap();
+                    let mine = rules.hand_for(elf, *result);
+                    n * (rules.hand_points(mine) + rules.result_points(*result))
+                })
+                .sum();
+            Interpretation {
+                meaning: Meaning::Results(mapping),
+                score,
+            }
+        })
+        .collect())
+}
+
+/// Scores `games` under every reading `rules` allows: one per ordering of
+/// the hands, and one per ordering of the results
+pub fn explore(rules: &RuleSet, games: &[Game]) -> Exploration {
+    let counts = tally(games);
+    Exploration {
+        hands: score_hands(rules, &counts),
+        results: score_results(rules, &counts),
+        rules: rules.clone(),
+    }
+}
+
+impl Exploration {
+    fn all(&self) -> impl Iterator<Item = &Interpretation> {
+        let hands = self.hands.as_deref().unwrap_or_default();
+        let results = self.results.as_deref().unwrap_or_default();
+        hands.iter().chain(results)
+    }
+
+    /// The reading worth the most. Hands come before results and the
+    /// puzzle's own reading before the rest, and the first wins a tie.
+    pub fn best(&self) -> Option<&Interpretation> {
+        self.all().min_by_key(|i| Reverse(i.score))
+    }
+
+    /// The reading worth the least, ties going the same way as
+    /// [`best`](Exploration::best)
+    pub fn worst(&self) -> Option<&Interpretation> {
+        self.all().min_by_key(|i| i.score)
+    }
+
+    /// Like `X=Rock Y=Paper Z=Scissors`
+    pub fn describe(&self, interpretation: &Interpretation) -> String {
+        let words: Vec<String> = match &interpretation.meaning {
+            Meaning::Hands(mapping) => mapping
+                .iter()
+                .map(|&(c, hand)| format!("{c}={}", self.rules.rule(hand).name))
+                .collect(),
+            Meaning::Results(mapping) => mapping
+                .iter()
+                .map(|(c, result)| format!("{c}={result}"))
+                .collect(),
+        };
+        words.join(" ")
+    }
+}
+
+/// ```text
+/// Second column as hands      Score
+/// X=Rock Y=Paper Z=Scissors       15  part 1
+/// ...
+/// Of them all                  Score
+/// X=Scissors Y=Paper Z=Rock       24  best
+/// ```
+impl Display for Exploration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .all()
+            .map(|i| self.describe(i).len())
+            .max()
+            .unwrap_or(0)
+            .max(22);
+        for (kind, interpretations, part) in [
+            ("hands", &self.hands, "part 1"),
+            ("results", &self.results, "part 2"),
+        ] {
+            writeln!(
+                f,
+                "{:<width$} {:>8}",
+                format!("Second column as {kind}"),
+                "Score"
+            )?;
+            match interpretations {
+                Ok(interpretations) => {
+                    for (n, i) in interpretations.iter().enumerate() {
+                        let note = if n == 0 {
+                            format!("  {part}")
+                        } else {
+                            String::new()
+                        };
+                        writeln!(f, "{:<width$} {:>8}{note}", self.describe(i), i.score)?;
+                    }
+                }
+                Err(e) => writeln!(f, "{e}")?,
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "{:<width$} {:>8}", "Of them all", "Score")?;
+        for (label, i) in [("best", self.best()), ("worst", self.worst())] {
+            if let Some(i) = i {
+                writeln!(f, "{:<width$} {:>8}  {label}", self.describe(i), i.score)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `aoc day 2 explore`: every reading of the guide, under the day's rules
+pub(crate) struct ExploreTool {
+    pub(crate) rules: RuleSet,
+}
+
+impl Tool for ExploreTool {
+    fn name(&self) -> &'static str {
+        "explore"
+    }
+
+    fn about(&self) -> &'static str {
+        "Score the guide under every reading of its second column"
+    }
+
+    fn run(&self, input: &mut dyn BufRead) -> eyre::Result<String> {
+        let mut text = String::new();
+        input.read_to_string(&mut text)?;
+        let games = parser::games(&text, &self.rules)?;
+        Ok(explore(&self.rules, &games).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    const SAMPLE: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn test_permutations() {
+        assert_eq!(
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0]
+            ],
+            permutations(3)
+        );
+        assert_eq!(120, permutations(5).len());
+        assert_eq!(vec![Vec::<usize>::new()], permutations(0));
+    }
+
+    /// The same rules with my letters handed out as `meaning` says, so that
+    /// part 1 or 2 reads the guide that way
+    fn relabelled(rules: &RuleSet, meaning: &Meaning) -> RuleSet {
+        let mut text = String::new();
+        for hand in rules.hands() {
+            let rule = rules.rule(hand);
+            let me = match meaning {
+                Meaning::Hands(mapping) => mapping.iter().find(|(_, h)| *h == hand).unwrap().0,
+                Meaning::Results(_) => rule.me,
+            };
+            text += &format!("hand {} {} {me} {}\n", rule.name, rule.elf, rule.points);
+        }
+        for (n, result) in RESULTS.into_iter().enumerate() {
+            let symbol = match meaning {
+                Meaning::Hands(_) => rules.result_symbols()[n],
+                Meaning::Results(mapping) => mapping.iter().find(|(_, r)| *r == result).unwrap().0,
+            };
+            text += &format!("{result} {symbol} {}\n", rules.result_points(result));
+        }
+        text.parse().unwrap()
+    }
+
+    fn check(rules: &RuleSet, input: &str) -> Result<Exploration, GameError> {
+        let games = parser::games(input, rules).unwrap();
+        let exploration = explore(rules, &games);
+        for i in exploration.all() {
+            let rules = relabelled(rules, &i.meaning);
+            let games = parser::games(input, &rules).unwrap();
+            let score: Result<i64, GameError> = match i.meaning {
+                Meaning::Hands(_) => games.iter().map(|g| g.score(&rules)).sum(),
+                Meaning::Results(_) => games.iter().map(|g| g.part2_score(&rules)).sum(),
+            };
+            assert_eq!(score?, i.score, "{}", exploration.describe(i));
+        }
+        Ok(exploration)
+    }
+
+    #[test]
+    fn test_sample() -> Result<(), GameError> {
+        let exploration = check(&RuleSet::classic(), SAMPLE)?;
+        let hands = exploration.hands.as_ref().unwrap();
+        let results = exploration.results.as_ref().unwrap();
+        assert_eq!((6, 6), (hands.len(), results.len()));
+        assert_eq!((15, 12), (hands[0].score, results[0].score));
+
+        // every line a win, which no reading as results manages
+        let best = exploration.best().unwrap();
+        assert_eq!(
+            ("X=Scissors Y=Paper Z=Rock".to_string(), 24),
+            (exploration.describe(best), best.score)
+        );
+        let worst = exploration.worst().unwrap();
+        assert_eq!(
+            ("X=Rock Y=Scissors Z=Paper".to_string(), 6),
+            (exploration.describe(worst), worst.score)
+        );
+        let text = exploration.to_string();
+        assert!(
+            text.contains("X=Rock Y=Paper Z=Scissors       15  part 1\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("X=loss Y=draw Z=win             12  part 2\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("X=Scissors Y=Paper Z=Rock       24  best\n"),
+            "{text}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rpsls() -> Result<(), GameError> {
+        let rules = RuleSet::rpsls();
+        let exploration = check(&rules, "A X\nB Y\nC Z\nD X\nE Y\nE Z\n")?;
+        assert_eq!(120, exploration.hands.as_ref().unwrap().len());
+        assert_eq!(6, exploration.results.as_ref().unwrap().len());
+
+        // V and W are only ever hands
+        let exploration = check(&rules, "A V\nB Z\n")?;
+        assert_eq!(Err(GameError::NotAResult('V')), exploration.results);
+        assert_eq!(120, exploration.all().count());
+        assert!(exploration.to_string().contains("'V' is a hand"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tool() -> eyre::Result<()> {
+        let tool = ExploreTool {
+            rules: RuleSet::classic(),
+        };
+        let text = tool.run(&mut SAMPLE.as_bytes())?;
+        assert_eq!(
+            explore(&tool.rules, &parser::games(SAMPLE, &tool.rules)?).to_string(),
+            text
+        );
+        assert!(tool.run(&mut "A Q\n".as_bytes()).is_err());
+        Ok(())
+    }
+}
@@ -1,108 +1,180 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::rules::RuleSet;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
 pub enum GameResult {
-    Win = 6,
-    Draw = 3,
-    Loss = 0,
+    Win,
+    Draw,
+    Loss,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Hand {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
+impl Display for GameResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameResult::Win => f.pad("win"),
+            GameResult::Draw => f.pad("draw"),
+            GameResult::Loss => f.pad("loss"),
+        }
+    }
 }
 
+/// One of the hands in a [`RuleSet`], which knows what it's called, what it
+/// scores and what it beats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hand(usize);
+
 impl Hand {
-    pub fn beats(&self) -> Self {
-        use Hand::*;
-        match *self {
-            Rock => Scissors,
-            Paper => Rock,
-            Scissors => Paper,
+    pub(crate) fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    /// Where it comes in the rule set's circle
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// "A, B or C"
+pub(crate) fn either(symbols: &[char]) -> String {
+    match symbols {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [rest @ .., last] => {
+            let rest: Vec<String> = rest.iter().map(char::to_string).collect();
+            format!("{} or {last}", rest.join(", "))
         }
     }
+}
+
+/// A line of the strategy guide that doesn't make sense
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// Not one of the elf's letters for a hand
+    ElfHand { found: char, expected: Vec<char> },
+    /// Not one of my letters for a hand, or for a result
+    Response { found: char, expected: Vec<char> },
+    /// Part 1 needs the second column to be a hand
+    NotAHand(char),
+    /// Part 2 needs the second column to be a result
+    NotAResult(char),
+    /// Anything that isn't two letters with a space between them
+    Shape(String),
+}
 
-    pub fn what_beats(&self) -> Self {
-        use Hand::*;
-        match *self {
-            Rock => Paper,
-            Paper => Scissors,
-            Scissors => Rock,
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::ElfHand { found, expected } => {
+                write!(f, "the elf plays {}, not {found:?}", either(expected))
+            }
+            GameError::Response { found, expected } => {
+                write!(
+                    f,
+                    "the second column is {}, not {found:?}",
+                    either(expected)
+                )
+            }
+            GameError::NotAHand(c) => write!(f, "{c:?} is a result, not a hand to play"),
+            GameError::NotAResult(c) => write!(f, "{c:?} is a hand, not how the round ends"),
+            GameError::Shape(line) => {
+                write!(f, "{line:?} isn't two letters with a space between them")
+            }
         }
     }
 }
 
+impl std::error::Error for GameError {}
+
+/// The elf's hand, and the second column, which part 1 reads as my hand and
+/// part 2 as how the round has to end. What that all means is up to the
+/// rules it's scored with.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Game {
     elf_hand: Hand,
-    my_hand: Hand,
-    needed_result: GameResult,
+    response: char,
 }
 
 impl Game {
-    pub fn new(elf_hand: Hand, my_hand: Hand) -> Self {
-        Self {
-            elf_hand,
-            my_hand,
-            needed_result: GameResult::Win,
+    /// Checks both columns mean something under `rules`
+    pub fn new(rules: &RuleSet, elf: char, response: char) -> Result<Self, GameError> {
+        let elf_hand = rules.elf_hand(elf).ok_or_else(|| GameError::ElfHand {
+            found: elf,
+            expected: rules.elf_symbols(),
+        })?;
+        let expected = rules.response_symbols();
+        if !expected.contains(&response) {
+            return Err(GameError::Response {
+                found: response,
+                expected,
+            });
         }
+        Ok(Self { elf_hand, response })
     }
 
-    pub fn from_chars(first: char, second: char) -> Self {
-        let elf_hand = match first {
-            'A' => Hand::Rock,
-            'B' => Hand::Paper,
-            'C' => Hand::Scissors,
-            _ => panic!("unsupported char {first:}"),
-        };
-        let my_hand = match second {
-            'X' => Hand::Rock,
-            'Y' => Hand::Paper,
-            'Z' => Hand::Scissors,
-            _ => panic!("unsupported char {second:}"),
-        };
-
-        let needed_result = match second {
-            'X' => GameResult::Loss,
-            'Y' => GameResult::Draw,
-            'Z' => GameResult::Win,
-            _ => panic!("unsupported char {second:}"),
-        };
-
-        Self {
-            elf_hand,
-            my_hand,
-            needed_result,
+    /// One line of the guide, like `A Y`
+    pub fn parse(rules: &RuleSet, line: &str) -> Result<Self, GameError> {
+        let mut chars = line.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(first), Some(' '), Some(second), None) => Game::new(rules, first, second),
+            _ => Err(GameError::Shape(line.to_string())),
         }
     }
 
-    fn my_result(&self) -> GameResult {
-        let elf_beats = self.elf_hand.beats();
-        let my_beats = self.my_hand.beats();
-        match (elf_beats, my_beats) {
-            _ if my_beats == self.elf_hand => GameResult::Win,
-            _ if elf_beats == self.my_hand => GameResult::Loss,
-            _ => GameResult::Draw,
-        }
+    pub fn elf_hand(&self) -> Hand {
+        self.elf_hand
     }
 
-    pub fn score(&self) -> i64 {
-        (self.my_result() as i64) + (self.my_hand as i64)
+    /// The second column's letter, whatever it turns out to mean
+    pub fn response(&self) -> char {
+        self.response
     }
 
-    fn part2_my_hand(&self) -> Hand {
-        let beats_elf = self.elf_hand.what_beats();
-        match self.needed_result {
-            GameResult::Win => self.elf_hand.what_beats(),
-            GameResult::Draw => self.elf_hand,
-            GameResult::Loss => self.elf_hand.beats(),
-        }
+    /// The second column as the hand I play, the way part 1 reads it
+    pub fn my_hand(&self, rules: &RuleSet) -> Result<Hand, GameError> {
+        rules
+            .my_hand(self.response)
+            .ok_or(GameError::NotAHand(self.response))
+    }
+
+    fn my_result(&self, rules: &RuleSet) -> Result<GameResult, GameError> {
+        Ok(rules.result(self.my_hand(rules)?, self.elf_hand))
+    }
+
+    pub fn score(&self, rules: &RuleSet) -> Result<i64, GameError> {
+        let my_hand = self.my_hand(rules)?;
+        let result = rules.result(my_hand, self.elf_hand);
+        Ok(rules.result_points(result) + rules.hand_points(my_hand))
+    }
+
+    fn needed_result(&self, rules: &RuleSet) -> Result<GameResult, GameError> {
+        rules
+            .result_for(self.response)
+            .ok_or(GameError::NotAResult(self.response))
+    }
+
+    pub fn part2_score(&self, rules: &RuleSet) -> Result<i64, GameError> {
+        let result = self.needed_result(rules)?;
+        let my_hand = rules.hand_for(self.elf_hand, result);
+        Ok(rules.result_points(result) + rules.hand_points(my_hand))
+    }
+}
+
+/// The elf's hand and the second column, under the classic rules
+impl TryFrom<(char, char)> for Game {
+    type Error = GameError;
+
+    fn try_from((first, second): (char, char)) -> Result<Self, GameError> {
+        Game::new(&RuleSet::classic(), first, second)
     }
+}
+
+/// One line of the guide under the classic rules
+impl FromStr for Game {
+    type Err = GameError;
 
-    pub fn part2_score(&self) -> i64 {
-        (self.needed_result as i64) + (self.part2_my_hand() as i64)
+    fn from_str(line: &str) -> Result<Self, GameError> {
+        Game::parse(&RuleSet::classic(), line)
     }
 }
 
@@ -110,62 +182,101 @@ impl Game {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part2_scores() {
-        assert_eq!(Game::from_chars('A', 'Y').part2_score(), 4);
-        assert_eq!(Game::from_chars('B', 'X').part2_score(), 1);
-        assert_eq!(Game::from_chars('C', 'Z').part2_score(), 7);
+    /// Game `elf` against `me`, both by name, under the classic rules
+    fn game(elf: &str, me: &str) -> (Game, RuleSet) {
+        let rules = RuleSet::classic();
+        let [elf, me] = [elf, me].map(|name| rules.hand(name).unwrap());
+        let game = Game::new(&rules, rules.rule(elf).elf, rules.rule(me).me).unwrap();
+        (game, rules)
+    }
+
+    fn score(elf: &str, me: &str) -> i64 {
+        let (game, rules) = game(elf, me);
+        game.score(&rules).unwrap()
+    }
+
+    fn result(elf: &str, me: &str) -> GameResult {
+        let (game, rules) = game(elf, me);
+        game.my_result(&rules).unwrap()
     }
 
     #[test]
-    fn test_scores() {
-        assert_eq!(Game::new(Hand::Rock, Hand::Rock).score(), 4);
-        assert_eq!(Game::new(Hand::Rock, Hand::Paper).score(), 8);
-        assert_eq!(Game::new(Hand::Rock, Hand::Scissors).score(), 3);
+    fn test_part2_scores() -> Result<(), GameError> {
+        let rules = RuleSet::classic();
+        assert_eq!(Game::try_from(('A', 'Y'))?.part2_score(&rules)?, 4);
+        assert_eq!(Game::try_from(('B', 'X'))?.part2_score(&rules)?, 1);
+        assert_eq!(Game::try_from(('C', 'Z'))?.part2_score(&rules)?, 7);
+        Ok(())
     }
 
     #[test]
-    fn test_wins() {
+    fn test_bad_games() {
+        let expected = vec!['X', 'Y', 'Z'];
+        assert_eq!(Game::try_from(('B', 'X')), "B X".parse());
         assert_eq!(
-            Game::new(Hand::Rock, Hand::Rock).my_result(),
-            GameResult::Draw
+            Err(GameError::ElfHand {
+                found: 'D',
+                expected: vec!['A', 'B', 'C']
+            }),
+            Game::try_from(('D', 'X'))
         );
         assert_eq!(
-            Game::new(Hand::Rock, Hand::Paper).my_result(),
-            GameResult::Win
+            Err(GameError::Response {
+                found: 'a',
+                expected: expected.clone()
+            }),
+            "A a".parse::<Game>()
         );
+        assert_eq!(Err(GameError::Shape("AX".into())), "AX".parse::<Game>());
+        assert_eq!(Err(GameError::Shape("A X ".into())), "A X ".parse::<Game>());
         assert_eq!(
-            Game::new(Hand::Rock, Hand::Scissors).my_result(),
-            GameResult::Loss
+            "the second column is X, Y or Z, not 'a'",
+            GameError::Response {
+                found: 'a',
+                expected
+            }
+            .to_string()
         );
+    }
 
-        assert_eq!(
-            Game::new(Hand::Paper, Hand::Rock).my_result(),
-            GameResult::Loss
-        );
-        assert_eq!(
-            Game::new(Hand::Paper, Hand::Paper).my_result(),
-            GameResult::Draw
-        );
-        assert_eq!(
-            Game::new(Hand::Paper, Hand::Scissors).my_result(),
-            GameResult::Win
-        );
+    #[test]
+    fn test_scores() {
+        assert_eq!(score("Rock", "Rock"), 4);
+        assert_eq!(score("Rock", "Paper"), 8);
+        assert_eq!(score("Rock", "Scissors"), 3);
+    }
 
+    #[test]
+    fn test_wins() {
+        assert_eq!(result("Rock", "Rock"), GameResult::Draw);
+        assert_eq!(result("Rock", "Paper"), GameResult::Win);
+        assert_eq!(result("Rock", "Scissors"), GameResult::Loss);
+
+        assert_eq!(result("Paper", "Rock"), GameResult::Loss);
+        assert_eq!(result("Paper", "Paper"), GameResult::Draw);
+        assert_eq!(result("Paper", "Scissors"), GameResult::Win);
+
+        assert_eq!(result("Scissors", "Rock"), GameResult::Win);
+        assert_eq!(result("Scissors", "Paper"), GameResult::Loss);
+        assert_eq!(result("Scissors", "Scissors"), GameResult::Draw);
+    }
+
+    #[test]
+    fn test_rpsls() -> Result<(), GameError> {
+        let rules = RuleSet::rpsls();
+        // the elf's scissors (E) against my Spock (W) wins, worth 6 + 2
+        assert_eq!(8, Game::parse(&rules, "E W")?.score(&rules)?);
+        // losing to the elf's lizard (D) takes the hand just before it,
+        // paper, worth 0 + 3
+        assert_eq!(3, Game::parse(&rules, "D X")?.part2_score(&rules)?);
         assert_eq!(
-            Game::new(Hand::Scissors, Hand::Rock).my_result(),
-            GameResult::Win
-        );
-        assert_eq!(
-            Game::new(Hand::Scissors, Hand::Paper).my_result(),
-            GameResult::Loss
+            Err(GameError::NotAResult('V')),
+            Game::parse(&rules, "A V")?.part2_score(&rules)
         );
         assert_eq!(
-            Game::new(Hand::Scissors, Hand::Scissors).my_result(),
-            GameResult::Draw
+            "the elf plays A, B, C, D or E, not 'F'",
+            Game::parse(&rules, "F X").unwrap_err().to_string()
         );
+        Ok(())
     }
-
-    #[test]
-    fn test_score() {}
 }
@@ -0,0 +1,58 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use eyre::{bail, ensure};
+
+use crate::rules::RuleSet;
+
+/// A strategy guide, one round per line. The second column sticks to
+/// letters that both parts can read, where the rules have any.
+pub(crate) struct InputGenerator {
+    rounds: usize,
+    rules: RuleSet,
+}
+
+impl InputGenerator {
+    pub(crate) fn new(rules: RuleSet) -> Self {
+        Self {
+            rounds: 2500,
+            rules,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new("rounds", "how many rounds", self.rounds)]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "rounds" => self.rounds = parse_param(name, value)?,
+            _ => bail!("day 2's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> eyre::Result<String> {
+        ensure!(self.rounds > 0, "there has to be at least one round");
+        let elf = self.rules.elf_symbols();
+        let mut responses = self.rules.response_symbols();
+        let both: Vec<char> = responses
+            .iter()
+            .copied()
+            .filter(|&c| self.rules.my_hand(c).is_some() && self.rules.result_for(c).is_some())
+            .collect();
+        if !both.is_empty() {
+            responses = both;
+        }
+        Ok((0..self.rounds)
+            .map(|_| {
+                let elf = rng.choose(&elf);
+                let me = rng.choose(&responses);
+                format!("{elf} {me}\n")
+            })
+            .collect())
+    }
+}
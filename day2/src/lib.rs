@@ -1,15 +1,112 @@
 #![allow(unused)]
-mod game;
+pub mod explore;
+pub mod game;
+mod generate;
 mod parser;
+pub mod rules;
+pub mod tournament;
 
-use std::fs;
+use aoc_common::{generate::Generator, tool::Tool, Param, Solution};
+use eyre::{bail, WrapErr};
+use game::Game;
+use rules::RuleSet;
 
-pub fn run() -> eyre::Result<()> {
-    let file_path = "../day2/input.txt";
-    let games = parser::games(file_path)?;
-    let score: i64 = games.iter().map(|g| g.score()).sum();
-    println!("Part 1 score is {score}");
-    let part2_score: i64 = games.iter().map(|g| g.part2_score()).sum();
-    println!("Part 2 score is {part2_score}");
-    Ok(())
+pub struct Day2 {
+    pub rules: RuleSet,
+    /// Where the rules came from, `classic`, `rpsls` or a file
+    rules_name: String,
+}
+
+impl Default for Day2 {
+    fn default() -> Self {
+        Self {
+            rules: RuleSet::classic(),
+            rules_name: "classic".to_string(),
+        }
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Input = Vec<Game>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> eyre::Result<Vec<Game>> {
+        Ok(parser::games(input, &self.rules)?)
+    }
+
+    fn part1(&self, games: &Vec<Game>) -> eyre::Result<i64> {
+        games.iter().map(|g| Ok(g.score(&self.rules)?)).sum()
+    }
+
+    fn part2(&self, games: &Vec<Game>) -> eyre::Result<i64> {
+        games.iter().map(|g| Ok(g.part2_score(&self.rules)?)).sum()
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new(
+            "rules",
+            "classic, rpsls or a file of rules",
+            &self.rules_name,
+        )]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "rules" => {
+                let text = match value {
+                    "classic" => rules::CLASSIC.to_string(),
+                    "rpsls" => rules::RPSLS.to_string(),
+                    path => std::fs::read_to_string(path)
+                        .wrap_err_with(|| format!("reading rules from {path}"))?,
+                };
+                self.rules = text.parse().wrap_err_with(|| format!("rules {value}"))?;
+                self.rules_name = value.to_string();
+            }
+            _ => bail!("day 2 has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::new(generate::InputGenerator::new(self.rules.clone())))
+    }
+
+    fn tools(&self) -> Vec<Box<dyn Tool>> {
+        vec![
+            Box::new(explore::ExploreTool {
+                rules: self.rules.clone(),
+            }),
+            Box::new(tournament::TournamentTool::new(self.rules.clone())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_param() -> eyre::Result<()> {
+        let mut day = Day2::default();
+        let games = day.parse("A Y\nB X\nC Z\n")?;
+        assert_eq!((15, 12), (day.part1(&games)?, day.part2(&games)?));
+
+        day.set_param("rules", "rpsls")?;
+        assert_eq!("rpsls", day.params()[0].value);
+        // my Spock beats rock, rock beats lizard and paper beats Spock
+        let games = day.parse("A W\nD V\nB X\n")?;
+        assert_eq!(8 + 7 + 9, day.part1(&games)?);
+        assert!(day.part2(&games).is_err());
+        // a loss to rock is scissors, a draw with Spock, a win over paper
+        let games = day.parse("A X\nB Y\nC Z\n")?;
+        assert_eq!(5 + 5 + 10, day.part2(&games)?);
+
+        assert!(day.set_param("rules", "no/such/file").is_err());
+        assert_eq!("rpsls", day.params()[0].value);
+        Ok(())
+    }
 }
@@ -1,64 +1,70 @@
-use std::fs;
-
+use aoc_common::parse::{parse_lines, Expected, IResult, NomError, ParseError};
 use nom::{
-    character::complete::{char, newline, one_of, space1},
-    combinator::map,
-    multi::many1,
-    sequence::{separated_pair, terminated},
-    IResult,
+    character::complete::{anychar, space1},
+    combinator::map_res,
+    sequence::separated_pair,
 };
 
-use crate::game::Game;
+use crate::{
+    game::{either, Game},
+    rules::RuleSet,
+};
 
-fn one_line(input: &str) -> IResult<&str, Game> {
-    map(
-        separated_pair(one_of("ABC"), space1, one_of("XYZ")),
-        |(e, m)| Game::from_chars(e, m),
-    )(input)
+/// One of `symbols`, or an error listing them
+fn one_of<'s>(symbols: &'s [char]) -> impl Fn(&str) -> IResult<&str, char> + 's {
+    move |input| match anychar::<_, NomError<&str>>(input) {
+        Ok((rest, c)) if symbols.contains(&c) => Ok((rest, c)),
+        _ => Err(nom::Err::Error(NomError {
+            input,
+            expected: Expected::Message(format!("expected {}", either(symbols))),
+        })),
+    }
 }
 
-fn whole_file(input: &str) -> IResult<&str, Vec<Game>> {
-    many1(terminated(one_line, newline))(input)
-}
-
-pub fn games(file_path: &str) -> eyre::Result<Vec<Game>> {
-    let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    match whole_file(&contents) {
-        Ok((_, games)) => Ok(games),
-        Err(e) => {
-            eprintln!("Error: {e:?}");
-            Err(eyre::eyre!("Went boom"))
-        }
-    }
+pub fn games(contents: &str, rules: &RuleSet) -> Result<Vec<Game>, ParseError> {
+    let elf = rules.elf_symbols();
+    let response = rules.response_symbols();
+    parse_lines(
+        contents,
+        map_res(
+            separated_pair(one_of(&elf), space1, one_of(&response)),
+            |(first, second)| Game::new(rules, first, second),
+        ),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::{Game, Hand};
     const INPUT: &str = "B X\nA Z\nA Y\n";
 
     #[test]
     fn check_one_line_works() -> eyre::Result<()> {
-        let x = one_line(INPUT)?;
-        assert_eq!(Game::from_chars('B', 'X'), x.1);
+        let x = games("B X", &RuleSet::classic())?;
+        assert_eq!(vec![Game::try_from(('B', 'X'))?], x);
         Ok(())
     }
 
     #[test]
     fn check_all() -> eyre::Result<()> {
-        let x = whole_file(INPUT)?;
-        let games = x.1;
+        let games = games(INPUT, &RuleSet::classic())?;
         assert_eq!(games.len(), 3);
         assert_eq!(
             vec![
-                Game::from_chars('B', 'X'),
-                Game::from_chars('A', 'Z'),
-                Game::from_chars('A', 'Y'),
+                Game::try_from(('B', 'X'))?,
+                Game::try_from(('A', 'Z'))?,
+                Game::try_from(('A', 'Y'))?,
             ],
             games
         );
 
         Ok(())
     }
+
+    #[test]
+    fn check_bad_line() {
+        let err = games("B X\nA Q\n", &RuleSet::classic()).unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("expected X, Y or Z", err.expected);
+    }
 }
@@ -0,0 +1,431 @@
+//! Games in the style of rock, paper, scissors with any odd number of
+//! hands, read from a short definition like [`CLASSIC`]:
+//!
+//! ```text
+//! hand Rock A X 1
+//! hand Paper B Y 2
+//! hand Scissors C Z 3
+//! loss X 0
+//! draw Y 3
+//! win Z 6
+//! ```
+//!
+//! A `hand` line is the hand's name, the elf's letter for it, mine, and
+//! what playing it scores. Hands go round in a circle, each beating the
+//! half of the others that come before it. The result lines give the
+//! letter part 2 reads as that result and what it scores. Blank lines and
+//! lines starting with `#` are skipped.
+
+use std::{fmt::Display, str::FromStr};
+
+use aoc_common::parse::{parse_lines, tag, IResult, ParseError};
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, char, i64, not_line_ending, satisfy, space1},
+    combinator::{eof, map, value},
+    sequence::{preceded, tuple},
+};
+
+use crate::game::{GameResult, Hand};
+
+/// The strategy guide's own rules
+pub const CLASSIC: &str = include_str!("../rules/classic.txt");
+/// Rock, paper, scissors, lizard, Spock
+pub const RPSLS: &str = include_str!("../rules/rpsls.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandRule {
+    pub name: String,
+    /// The elf's letter for this hand
+    pub elf: char,
+    /// My letter for this hand
+    pub me: char,
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResultRule {
+    /// The letter part 2 reads as this result
+    pub symbol: char,
+    pub points: i64,
+}
+
+/// A definition that doesn't make a game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    Parse(ParseError),
+    /// Only an odd number of hands, 3 or more, can all beat the same number
+    /// of others
+    Hands(usize),
+    /// The same letter for two hands, or for two results
+    Symbol(char),
+    MissingResult(GameResult),
+    TwiceResult(GameResult),
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleError::Parse(e) => write!(f, "{e}"),
+            RuleError::Hands(n) => {
+                write!(
+                    f,
+                    "a game needs an odd number of hands, at least 3, not {n}"
+                )
+            }
+            RuleError::Symbol(c) => write!(f, "{c:?} means two things in the same column"),
+            RuleError::MissingResult(result) => write!(f, "there's no {result} line"),
+            RuleError::TwiceResult(result) => write!(f, "there's more than one {result} line"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+impl From<ParseError> for RuleError {
+    fn from(e: ParseError) -> Self {
+        RuleError::Parse(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    /// In the order they go round
+    hands: Vec<HandRule>,
+    /// Loss, draw and win
+    results: [ResultRule; 3],
+}
+
+fn result_index(result: GameResult) -> usize {
+    match result {
+        GameResult::Loss => 0,
+        GameResult::Draw => 1,
+        GameResult::Win => 2,
+    }
+}
+
+/// In the order a rule set keeps them
+pub(crate) const RESULTS: [GameResult; 3] = [GameResult::Loss, GameResult::Draw, GameResult::Win];
+
+impl RuleSet {
+    pub fn classic() -> Self {
+        CLASSIC.parse().expect("the classic rules to make sense")
+    }
+
+    pub fn rpsls() -> Self {
+        RPSLS.parse().expect("the RPSLS rules to make sense")
+    }
+
+    pub fn hand_count(&self) -> usize {
+        self.hands.len()
+    }
+
+    pub fn hands(&self) -> impl Iterator<Item = Hand> {
+        (0..self.hands.len()).map(Hand::new)
+    }
+
+    pub fn rule(&self, hand: Hand) -> &HandRule {
+        &self.hands[hand.index()]
+    }
+
+    /// The hand called `name`, like `Rock`
+    pub fn hand(&self, name: &str) -> Option<Hand> {
+        self.hands
+            .iter()
+            .position(|h| h.name == name)
+            .map(Hand::new)
+    }
+
+    pub fn elf_hand(&self, symbol: char) -> Option<Hand> {
+        self.hands
+            .iter()
+            .position(|h| h.elf == symbol)
+            .map(Hand::new)
+    }
+
+    pub fn my_hand(&self, symbol: char) -> Option<Hand> {
+        self.hands
+            .iter()
+            .position(|h| h.me == symbol)
+            .map(Hand::new)
+    }
+
+    pub fn result_for(&self, symbol: char) -> Option<GameResult> {
+        RESULTS
+            .into_iter()
+            .find(|&r| self.results[result_index(r)].symbol == symbol)
+    }
+
+    pub fn elf_symbols(&self) -> Vec<char> {
+        self.hands.iter().map(|h| h.elf).collect()
+    }
+
+    /// My letters for the hands, in the order they go round
+    pub fn hand_symbols(&self) -> Vec<char> {
+        self.hands.iter().map(|h| h.me).collect()
+    }
+
+    /// The letters for a loss, a draw and a win
+    pub fn result_symbols(&self) -> Vec<char> {
+        self.results.iter().map(|r| r.symbol).collect()
+    }
+
+    /// Everything the second column can say, as a hand or a result
+    pub fn response_symbols(&self) -> Vec<char> {
+        let mut symbols: Vec<char> = self.hands.iter().map(|h| h.me).collect();
+        for result in &self.results {
+            if !symbols.contains(&result.symbol) {
+                symbols.push(result.symbol);
+            }
+        }
+        symbols
+    }
+
+    pub fn hand_points(&self, hand: Hand) -> i64 {
+        self.rule(hand).points
+    }
+
+    pub fn result_points(&self, result: GameResult) -> i64 {
+        self.results[result_index(result)].points
+    }
+
+    /// Whether `a` beats `b`: it does when `b` is in the half of the
+    /// circle just before `a`
+    pub fn beats(&self, a: Hand, b: Hand) -> bool {
+        let n = self.hand_count();
+        let behind = (a.index() + n - b.index()) % n;
+        (1..=n / 2).contains(&behind)
+    }
+
+    /// How it goes for `mine` against `theirs`
+    pub fn result(&self, mine: Hand, theirs: Hand) -> GameResult {
+        match () {
+            _ if self.beats(mine, theirs) => GameResult::Win,
+            _ if self.beats(theirs, mine) => GameResult::Loss,
+            _ => GameResult::Draw,
+        }
+    }
+
+    /// A hand that gets `result` against `theirs`. With more than three
+    /// hands there's a choice, and it's the one next to `theirs`.
+    pub fn hand_for(&self, theirs: Hand, result: GameResult) -> Hand {
+        let n = self.hand_count();
+        match result {
+            GameResult::Win => Hand::new((theirs.index() + 1) % n),
+            GameResult::Draw => theirs,
+            GameResult::Loss => Hand::new((theirs.index() + n - 1) % n),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Line {
+    Hand(HandRule),
+    Result(GameResult, ResultRule),
+    Nothing,
+}
+
+fn symbol(input: &str) -> IResult<&str, char> {
+    satisfy(|c| !c.is_whitespace())(input)
+}
+
+fn hand_line(input: &str) -> IResult<&str, Line> {
+    map(
+        tuple((
+            preceded(tuple((tag("hand"), space1)), alpha1),
+            preceded(space1, symbol),
+            preceded(space1, symbol),
+            preceded(space1, i64),
+        )),
+        |(name, elf, me, points)| {
+            Line::Hand(HandRule {
+                name: name.to_string(),
+                elf,
+                me,
+                points,
+            })
+        },
+    )(input)
+}
+
+fn result_line(input: &str) -> IResult<&str, Line> {
+    map(
+        tuple((
+            alt((
+                value(GameResult::Loss, tag("loss")),
+                value(GameResult::Draw, tag("draw")),
+                value(GameResult::Win, tag("win")),
+            )),
+            preceded(space1, symbol),
+            preceded(space1, i64),
+        )),
+        |(result, symbol, points)| Line::Result(result, ResultRule { symbol, points }),
+    )(input)
+}
+
+fn line(input: &str) -> IResult<&str, Line> {
+    alt((
+        hand_line,
+        result_line,
+        value(Line::Nothing, preceded(char('#'), not_line_ending)),
+        value(Line::Nothing, eof),
+    ))(input)
+}
+
+fn check_symbols(symbols: impl Iterator<Item = char>) -> Result<(), RuleError> {
+    let mut seen = Vec::new();
+    for c in symbols {
+        if seen.contains(&c) {
+            return Err(RuleError::Symbol(c));
+        }
+        seen.push(c);
+    }
+    Ok(())
+}
+
+impl FromStr for RuleSet {
+    type Err = RuleError;
+
+    fn from_str(s: &str) -> Result<Self, RuleError> {
+        let mut hands = Vec::new();
+        let mut results = [None; 3];
+        for line in parse_lines(s, line)? {
+            match line {
+                Line::Hand(hand) => hands.push(hand),
+                Line::Result(result, rule) => {
+                    if results[result_index(result)].replace(rule).is_some() {
+                        return Err(RuleError::TwiceResult(result));
+                    }
+                }
+                Line::Nothing => {}
+            }
+        }
+        if hands.len() < 3 || hands.len() % 2 == 0 {
+            return Err(RuleError::Hands(hands.len()));
+        }
+        check_symbols(hands.iter().map(|h| h.elf))?;
+        check_symbols(hands.iter().map(|h| h.me))?;
+        let mut found = [ResultRule {
+            symbol: ' ',
+            points: 0,
+        }; 3];
+        for result in RESULTS {
+            found[result_index(result)] =
+                results[result_index(result)].ok_or(RuleError::MissingResult(result))?;
+        }
+        check_symbols(found.iter().map(|r| r.symbol))?;
+        Ok(Self {
+            hands,
+            results: found,
+        })
+    }
+}
+
+/// Back into a definition, so that parsing it gets the same rules
+impl Display for RuleSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for h in &self.hands {
+            writeln!(f, "hand {} {} {} {}", h.name, h.elf, h.me, h.points)?;
+        }
+        for result in RESULTS {
+            let rule = self.results[result_index(result)];
+            writeln!(f, "{result} {} {}", rule.symbol, rule.points)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classic() {
+        let rules = RuleSet::classic();
+        let [rock, paper, scissors] = ["Rock", "Paper", "Scissors"].map(|n| rules.hand(n).unwrap());
+        assert!(rules.beats(paper, rock));
+        assert!(rules.beats(rock, scissors));
+        assert!(!rules.beats(rock, paper));
+        assert!(!rules.beats(rock, rock));
+        assert_eq!(Some(scissors), rules.elf_hand('C'));
+        assert_eq!(Some(GameResult::Draw), rules.result_for('Y'));
+        assert_eq!(vec!['X', 'Y', 'Z'], rules.response_symbols());
+        assert_eq!(rock, rules.hand_for(scissors, GameResult::Win));
+        assert_eq!(paper, rules.hand_for(scissors, GameResult::Loss));
+    }
+
+    #[test]
+    fn test_rpsls() {
+        let rules = RuleSet::rpsls();
+        let hand = |name| rules.hand(name).unwrap();
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert!(rules.beats(hand(winner), hand(loser)), "{winner} {loser}");
+            assert_eq!(GameResult::Loss, rules.result(hand(loser), hand(winner)));
+        }
+        for a in rules.hands() {
+            assert_eq!(2, rules.hands().filter(|&b| rules.beats(a, b)).count());
+            for result in RESULTS {
+                assert_eq!(result, rules.result(rules.hand_for(a, result), a));
+            }
+        }
+        assert_eq!(None, rules.result_for('V'));
+        assert_eq!(vec!['V', 'W', 'X', 'Y', 'Z'], rules.response_symbols());
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), RuleError> {
+        for text in [CLASSIC, RPSLS] {
+            let rules: RuleSet = text.parse()?;
+            assert_eq!(rules, rules.to_string().parse()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_rules() {
+        let hands = "hand Rock A X 1\nhand Paper B Y 2\nhand Scissors C Z 3\n";
+        let results = "loss X 0\ndraw Y 3\nwin Z 6\n";
+        let parse = |text: &str| text.parse::<RuleSet>();
+        assert_eq!(
+            Err(RuleError::Hands(2)),
+            parse(&format!("hand Rock A X 1\nhand Paper B Y 2\n{results}"))
+        );
+        assert_eq!(
+            Err(RuleError::Hands(4)),
+            parse(&format!("{hands}hand Well D W 4\n{results}"))
+        );
+        assert_eq!(
+            Err(RuleError::Symbol('B')),
+            parse(&format!(
+                "{hands}hand Well B W 4\nhand Spock E V 5\n{results}"
+            ))
+        );
+        assert_eq!(
+            Err(RuleError::MissingResult(GameResult::Win)),
+            parse(&format!("{hands}loss X 0\ndraw Y 3\n"))
+        );
+        assert_eq!(
+            Err(RuleError::TwiceResult(GameResult::Draw)),
+            parse(&format!("{hands}{results}draw X 3\n"))
+        );
+        assert_eq!(
+            Err(RuleError::Symbol('X')),
+            parse(&format!("{hands}loss X 0\ndraw X 3\nwin Z 6\n"))
+        );
+        let Err(RuleError::Parse(e)) = parse(&format!("{hands}win Z lots\n")) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((4, 7), (e.line, e.column));
+    }
+}
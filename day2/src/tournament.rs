@@ -0,0 +1,480 @@
+//! Strategies playing each other for as many rounds as you like, every one
+//! against every other. Each side's round is scored as a [`Game`] with the
+//! other side as the elf, the way part 1 scores a line of the guide.
+
+use std::{cmp::Reverse, fmt::Display, io::BufRead};
+
+use aoc_common::{generate::Rng, parse_param, tool::Tool, Param};
+use eyre::{bail, ensure};
+
+use crate::{
+    game::{Game, GameError, GameResult, Hand},
+    parser,
+    rules::RuleSet,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// The same hand every round
+    Fixed(Hand),
+    /// Any hand, all as likely
+    Random,
+    /// Whatever beats the hand the other side has played most so far
+    Frequency,
+    /// The other side's last hand
+    Copy,
+    /// The guide's second column as hands, from the top and round again
+    Guide(Vec<Hand>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrategyError {
+    Unknown(String),
+    EmptyGuide,
+    /// A line of the guide without a hand to play
+    Guide(GameError),
+}
+
+impl Display for StrategyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrategyError::Unknown(name) => {
+                write!(f, "{name:?} isn't random, frequency, copy, guide or a hand")
+            }
+            StrategyError::EmptyGuide => write!(f, "the guide has no rounds to play"),
+            StrategyError::Guide(e) => write!(f, "playing the guide: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for StrategyError {}
+
+impl Strategy {
+    /// `random`, `frequency`, `copy`, `guide`, or the name of a hand to
+    /// always play, in any case. `guide` plays the second column of
+    /// `guide`.
+    pub fn parse(rules: &RuleSet, name: &str, guide: &[Game]) -> Result<Self, StrategyError> {
+        let strategy = match name.to_lowercase().as_str() {
+            "random" => Strategy::Random,
+            "frequency" => Strategy::Frequency,
+            "copy" => Strategy::Copy,
+            "guide" if guide.is_empty() => return Err(StrategyError::EmptyGuide),
+            "guide" => Strategy::Guide(
+                guide
+                    .iter()
+                    .map(|g| g.my_hand(rules))
+                    .collect::<Result<_, _>>()
+                    .map_err(StrategyError::Guide)?,
+            ),
+            lower => rules
+                .hands()
+                .find(|&h| rules.rule(h).name.to_lowercase() == lower)
+                .map(Strategy::Fixed)
+                .ok_or_else(|| StrategyError::Unknown(name.to_string()))?,
+        };
+        Ok(strategy)
+    }
+
+    pub fn name(&self, rules: &RuleSet) -> String {
+        match self {
+            Strategy::Fixed(hand) => rules.rule(*hand).name.clone(),
+            Strategy::Random => "random".to_string(),
+            Strategy::Frequency => "frequency".to_string(),
+            Strategy::Copy => "copy".to_string(),
+            Strategy::Guide(_) => "guide".to_string(),
+        }
+    }
+}
+
+/// Every hand always played, then the rest
+pub fn default_strategies(rules: &RuleSet) -> Vec<String> {
+    rules
+        .hands()
+        .map(|h| rules.rule(h).name.clone())
+        .chain(["random", "frequency", "copy", "guide"].map(String::from))
+        .collect()
+}
+
+/// A strategy partway through a match, and what it's seen of the other side
+struct Player<'s> {
+    strategy: &'s Strategy,
+    rng: Rng,
+    /// How many times the other side played each hand
+    seen: Vec<u64>,
+    last: Option<Hand>,
+    round: usize,
+}
+
+impl<'s> Player<'s> {
+    fn new(strategy: &'s Strategy, rules: &RuleSet, seed: u64) -> Self {
+        Self {
+            strategy,
+            rng: Rng::new(seed),
+            seen: vec![0; rules.hand_count()],
+            last: None,
+            round: 0,
+        }
+    }
+
+    /// Before it knows anything about the other side, a strategy that
+    /// follows it plays the first hand
+    fn play(&mut self, rules: &RuleSet) -> Hand {
+        let first = Hand::new(0);
+        let hand = match self.strategy {
+            Strategy::Fixed(hand) => *hand,
+            Strategy::Random => Hand::new(self.rng.index(rules.hand_count())),
+            Strategy::Frequency => match self.last {
+                None => first,
+                Some(_) => {
+                    let most = rules
+                        .hands()
+                        .min_by_key(|h| Reverse(self.seen[h.index()]))
+                        .unwrap_or(first);
+                    rules.hand_for(most, GameResult::Win)
+                }
+            },
+            Strategy::Copy => self.last.unwrap_or(first),
+            Strategy::Guide(hands) => hands[self.round % hands.len()],
+        };
+        self.round += 1;
+        hand
+    }
+
+    fn saw(&mut self, theirs: Hand) {
+        self.seen[theirs.index()] += 1;
+        self.last = Some(theirs);
+    }
+}
+
+/// How one strategy did over all its matches
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Record {
+    pub name: String,
+    pub rounds: u64,
+    pub wins: u64,
+    pub draws: u64,
+    pub losses: u64,
+    pub points: i64,
+}
+
+impl Record {
+    fn add(&mut self, result: GameResult, points: i64) {
+        self.rounds += 1;
+        self.points += points;
+        match result {
+            GameResult::Win => self.wins += 1,
+            GameResult::Draw => self.draws += 1,
+            GameResult::Loss => self.losses += 1,
+        }
+    }
+
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.rounds > 0).then(|| self.wins as f64 / self.rounds as f64)
+    }
+
+    /// Points per round
+    pub fn expected_score(&self) -> Option<f64> {
+        (self.rounds > 0).then(|| self.points as f64 / self.rounds as f64)
+    }
+}
+
+/// What `mine` scores against `theirs`, as a line of the guide would
+fn score(rules: &RuleSet, mine: Hand, theirs: Hand) -> i64 {
+    Game::new(rules, rules.rule(theirs).elf, rules.rule(mine).me)
+        .and_then(|game| game.score(rules))
+        .expect("every hand to have letters that make a game")
+}
+
+/// Every strategy plays every other for `rounds` rounds. The same seed
+/// always plays out the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tournament {
+    pub rounds: u64,
+    pub seed: u64,
+}
+
+/// A record for each strategy, in the order they were entered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standings(pub Vec<Record>);
+
+impl Tournament {
+    pub fn play(&self, rules: &RuleSet, strategies: &[Strategy]) -> Standings {
+        let mut records: Vec<Record> = strategies
+            .iter()
+            .map(|s| Record {
+                name: s.name(rules),
+                ..Record::default()
+            })
+            .collect();
+        let mut seeds = Rng::new(self.seed);
+        for a in 0..strategies.len() {
+            for b in a + 1..strategies.len() {
+                let mut left = Player::new(&strategies[a], rules, seeds.next_u64());
+                let mut right = Player::new(&strategies[b], rules, seeds.next_u64());
+                for _ in 0..self.rounds {
+                    let (l, r) = (left.play(rules), right.play(rules));
+                    left.saw(r);
+                    right.saw(l);
+                    records[a].add(rules.result(l, r), score(rules, l, r));
+                    records[b].add(rules.result(r, l), score(rules, r, l));
+                }
+            }
+        }
+        Standings(records)
+    }
+}
+
+/// Best score per round first
+impl Display for Standings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut records: Vec<&Record> = self.0.iter().collect();
+        records.sort_by(|a, b| {
+            let score = |r: &Record| r.expected_score().unwrap_or(f64::NEG_INFINITY);
+            score(b).total_cmp(&score(a))
+        });
+        writeln!(
+            f,
+            "{:<12} {:>9} {:>9} {:>9} {:>9} {:>9} {:>9}",
+            "Strategy", "Rounds", "Wins", "Draws", "Losses", "Win rate", "Score"
+        )?;
+        for r in records {
+            let win_rate = r
+                .win_rate()
+                .map_or("-".to_string(), |w| format!("{:.1}%", w * 100.0));
+            let score = r
+                .expected_score()
+                .map_or("-".to_string(), |s| format!("{s:.3}"));
+            writeln!(
+                f,
+                "{:<12} {:>9} {:>9} {:>9} {:>9} {win_rate:>9} {score:>9}",
+                r.name, r.rounds, r.wins, r.draws, r.losses
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// `aoc day 2 tournament`: the strategies named in its knobs play each
+/// other, `guide` playing the input
+pub(crate) struct TournamentTool {
+    rules: RuleSet,
+    tournament: Tournament,
+    strategies: Vec<String>,
+}
+
+impl TournamentTool {
+    pub(crate) fn new(rules: RuleSet) -> Self {
+        Self {
+            strategies: default_strategies(&rules),
+            tournament: Tournament {
+                rounds: 1000,
+                seed: 0,
+            },
+            rules,
+        }
+    }
+}
+
+impl Tool for TournamentTool {
+    fn name(&self) -> &'static str {
+        "tournament"
+    }
+
+    fn about(&self) -> &'static str {
+        "Play strategies against each other"
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "rounds",
+                "rounds each pair of strategies plays",
+                self.tournament.rounds,
+            ),
+            Param::new("seed", "same seed, same tournament", self.tournament.seed),
+            Param::new(
+                "strategies",
+                "hands, random, frequency, copy or guide, split by commas",
+                self.strategies.join(","),
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "rounds" => self.tournament.rounds = parse_param(name, value)?,
+            "seed" => self.tournament.seed = parse_param(name, value)?,
+            "strategies" => {
+                let strategies: Vec<String> = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .collect();
+                ensure!(!strategies.is_empty(), "a tournament needs strategies");
+                self.strategies = strategies;
+            }
+            _ => bail!("tournament has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn run(&self, input: &mut dyn BufRead) -> eyre::Result<String> {
+        let mut text = String::new();
+        input.read_to_string(&mut text)?;
+        let guide = parser::games(&text, &self.rules)?;
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|name| Strategy::parse(&self.rules, name, &guide))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.tournament.play(&self.rules, &strategies).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    fn strategies(rules: &RuleSet, names: &[&str]) -> Vec<Strategy> {
+        let guide = parser::games("A Y\nB X\nC Z\n", rules).unwrap();
+        names
+            .iter()
+            .map(|name| Strategy::parse(rules, name, &guide).unwrap())
+            .collect()
+    }
+
+    fn play(names: &[&str], rounds: u64) -> Vec<Record> {
+        let rules = RuleSet::classic();
+        let strategies = strategies(&rules, names);
+        Tournament { rounds, seed: 7 }.play(&rules, &strategies).0
+    }
+
+    #[test]
+    fn test_followers() {
+        // rock draws once, then frequency has it worked out
+        let records = play(&["rock", "frequency"], 100);
+        assert_eq!(
+            (0, 1, 99),
+            (records[0].wins, records[0].draws, records[0].losses)
+        );
+        assert_eq!(99 * 8 + 4, records[1].points);
+        assert_eq!(Some(0.99), records[1].win_rate());
+
+        // copy starts with rock, and then plays paper like paper does
+        let records = play(&["paper", "copy"], 10);
+        assert_eq!(
+            (1, 9, 0),
+            (records[0].wins, records[0].draws, records[0].losses)
+        );
+        assert_eq!(Some(5.3), records[0].expected_score());
+    }
+
+    #[test]
+    fn test_guide() {
+        // paper, rock, scissors against rock: a win, a draw and a loss
+        let records = play(&["guide", "rock"], 7);
+        assert_eq!(
+            (3, 2, 2),
+            (records[0].wins, records[0].draws, records[0].losses)
+        );
+        assert_eq!(3 * 8 + 2 * 4 + 2 * 3, records[0].points);
+        assert_eq!(records[0].wins, records[1].losses);
+    }
+
+    #[test]
+    fn test_seeded() {
+        let names = ["random", "random", "scissors"];
+        let records = play(&names, 30_000);
+        assert_eq!(records, play(&names, 30_000));
+        for r in &records {
+            assert_eq!(60_000, r.rounds);
+            let win_rate = r.win_rate().unwrap();
+            assert!((0.32..0.35).contains(&win_rate), "{r:?}");
+        }
+        assert_ne!(records[0], records[1]);
+        let rules = RuleSet::classic();
+        let strategies = strategies(&rules, &names);
+        assert_ne!(
+            records,
+            Tournament {
+                rounds: 30_000,
+                seed: 8
+            }
+            .play(&rules, &strategies)
+            .0
+        );
+    }
+
+    #[test]
+    fn test_every_pair() {
+        let rules = RuleSet::rpsls();
+        let names = default_strategies(&rules);
+        assert_eq!(9, names.len());
+        let guide = parser::games("A V\nB Z\n", &rules).unwrap();
+        let strategies: Vec<Strategy> = names
+            .iter()
+            .map(|name| Strategy::parse(&rules, name, &guide).unwrap())
+            .collect();
+        let standings = Tournament { rounds: 5, seed: 0 }.play(&rules, &strategies);
+        for r in &standings.0 {
+            assert_eq!(8 * 5, r.rounds);
+            assert_eq!(r.rounds, r.wins + r.draws + r.losses);
+        }
+        let total = |f: fn(&Record) -> u64| standings.0.iter().map(f).sum::<u64>();
+        assert_eq!(total(|r| r.wins), total(|r| r.losses));
+
+        let text = standings.to_string();
+        assert!(text.starts_with("Strategy        Rounds"), "{text}");
+        assert!(text.contains("\nSpock               40"), "{text}");
+    }
+
+    #[test]
+    fn test_bad_strategies() {
+        let rules = RuleSet::classic();
+        assert_eq!(
+            Ok(Strategy::Fixed(rules.hand("Paper").unwrap())),
+            Strategy::parse(&rules, "PAPER", &[])
+        );
+        assert_eq!(
+            Err(StrategyError::Unknown("lizard".into())),
+            Strategy::parse(&rules, "lizard", &[])
+        );
+        assert_eq!(
+            Err(StrategyError::EmptyGuide),
+            Strategy::parse(&rules, "guide", &[])
+        );
+        // X is only ever a result here
+        let rules: RuleSet =
+            "hand Rock A R 1\nhand Paper B P 2\nhand Scissors C S 3\nloss X 0\ndraw Y 3\nwin Z 6\n"
+                .parse()
+                .unwrap();
+        let guide = parser::games("A R\nB X\n", &rules).unwrap();
+        assert_eq!(
+            Err(StrategyError::Guide(GameError::NotAHand('X'))),
+            Strategy::parse(&rules, "guide", &guide)
+        );
+    }
+
+    #[test]
+    fn test_tool() -> eyre::Result<()> {
+        let mut tool = TournamentTool::new(RuleSet::classic());
+        assert_eq!(
+            "rock,paper,scissors,random,frequency,copy,guide",
+            tool.params()[2].value.to_lowercase()
+        );
+        tool.set_param("strategies", "rock, guide")?;
+        tool.set_param("rounds", "3")?;
+        let text = tool.run(&mut "A Y\n".as_bytes())?;
+        // the guide plays paper, every round
+        assert!(
+            text.contains("\nguide                3         3"),
+            "{text}"
+        );
+        assert!(tool.set_param("strategies", ",").is_err());
+        tool.set_param("strategies", "lizard")?;
+        assert!(tool.run(&mut "A Y\n".as_bytes()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::Solution;
+use eyre::bail;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input = String;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> eyre::Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, _input: &String) -> eyre::Result<i64> {
+        bail!("day 20 part 1 is not solved yet")
+    }
+
+    fn part2(&self, _input: &String) -> eyre::Result<i64> {
+        bail!("day 20 part 2 is not solved yet")
+    }
+}
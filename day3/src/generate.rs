@@ -0,0 +1,76 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use eyre::{bail, ensure};
+
+use crate::ALPHABET;
+
+/// Groups of three rucksacks. Each rucksack has exactly one item in both
+/// compartments and each group has exactly one item in all three, so
+/// every letter outside the badge is kept to one rucksack of the group.
+pub(crate) struct InputGenerator {
+    groups: usize,
+    items: usize,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            groups: 100,
+            items: 24,
+        }
+    }
+}
+
+impl InputGenerator {
+    fn rucksack(&self, rng: &mut Rng, badge: char, letters: &[char]) -> String {
+        let (shared, rest) = letters.split_first().unwrap();
+        let (left_only, right_only) = rest.split_at(rest.len() / 2);
+        let size = 2 + rng.index(self.items - 1);
+        let mut left = vec![*shared, badge];
+        left.extend((2..size).map(|_| *rng.choose(left_only)));
+        let mut right = vec![*shared];
+        right.extend((1..size).map(|_| *rng.choose(right_only)));
+        rng.shuffle(&mut left);
+        rng.shuffle(&mut right);
+        left.into_iter().chain(right).collect()
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("groups", "how many groups of three elves", self.groups),
+            Param::new("items", "most items in one compartment", self.items),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "groups" => self.groups = parse_param(name, value)?,
+            "items" => self.items = parse_param(name, value)?,
+            _ => bail!("day 3's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> eyre::Result<String> {
+        ensure!(self.groups > 0, "there has to be at least one group");
+        ensure!(
+            self.items >= 2,
+            "a compartment needs room for the shared item and the badge"
+        );
+        let mut input = String::new();
+        for _ in 0..self.groups {
+            let mut letters: Vec<char> = ALPHABET.chars().collect();
+            rng.shuffle(&mut letters);
+            let (badge, rest) = letters.split_first().unwrap();
+            for own in rest.chunks(rest.len() / 3) {
+                input += &self.rucksack(rng, *badge, own);
+                input.push('\n');
+            }
+        }
+        Ok(input)
+    }
+}
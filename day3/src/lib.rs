@@ -1,46 +1,92 @@
-use std::fs;
-
+mod generate;
 mod parser;
 
-use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_common::{generate::Generator, Solution};
 
 const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
-fn score(items: Vec<char>) -> usize {
-    let alphabet_scores: HashMap<char, usize> = ALPHABET
-        .chars()
-        .enumerate()
-        .map(|(pos, e)| (e, pos + 1))
-        .collect();
+/// Rucksacks that don't pack the way the elves say they do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemError {
+    NotALetter(char),
+    /// The two compartments hold as many items as each other
+    OddRucksack(usize),
+    /// Exactly one kind of item ends up in both compartments
+    NothingShared,
+    /// Each group of three has one item in common, its badge
+    NoBadge,
+}
+
+impl Display for ItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemError::NotALetter(c) => {
+                write!(f, "{c:?} isn't an item, those are a to z and A to Z")
+            }
+            ItemError::OddRucksack(n) => {
+                write!(f, "{n} items don't split into two compartments")
+            }
+            ItemError::NothingShared => write!(f, "no item is in both compartments"),
+            ItemError::NoBadge => write!(f, "no item is in all three rucksacks"),
+        }
+    }
+}
+
+impl std::error::Error for ItemError {}
 
+/// a to z are 1 to 26, A to Z are 27 to 52
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Priority(usize);
+
+impl TryFrom<char> for Priority {
+    type Error = ItemError;
+
+    fn try_from(c: char) -> Result<Self, ItemError> {
+        let pos = ALPHABET.find(c).ok_or(ItemError::NotALetter(c))?;
+        Ok(Priority(pos + 1))
+    }
+}
+
+fn score(items: Vec<char>) -> Result<usize, ItemError> {
     items
         .into_iter()
-        .map(|c| alphabet_scores.get(&c).unwrap())
+        .map(|c| Priority::try_from(c).map(|p| p.0))
         .sum()
 }
 
 fn groups_score(contents: &str) -> eyre::Result<usize> {
-    let alphabet_scores: HashMap<char, usize> = ALPHABET
-        .chars()
-        .enumerate()
-        .map(|(pos, e)| (e, pos + 1))
-        .collect();
-
     let groups = parser::parse_to_groups(contents)?;
-    let sum = groups
-        .iter()
-        .map(|b| alphabet_scores.get(b).unwrap())
-        .sum();
-    Ok(sum)
+    Ok(score(groups)?)
 }
 
-pub fn run() -> eyre::Result<()> {
-    let file_path = "../day3/input.txt";
-    let contents = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    let chars = parser::parse_file(&contents)?;
-    println!("Part 1 score: {}", score(chars));
-    println!("Part 2 score: {}", groups_score(&contents)?);
-    Ok(())
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    // part 2 regroups the raw lines, so there's not much to parse up front
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> eyre::Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, contents: &String) -> eyre::Result<usize> {
+        Ok(score(parser::parse_file(contents)?)?)
+    }
+
+    fn part2(&self, contents: &String) -> eyre::Result<usize> {
+        groups_score(contents)
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
 }
 
 #[cfg(test)]
@@ -66,7 +112,17 @@ CrZsJsPPZsGzwwsLwLmpwMDw
     #[test]
     fn test_the_score() -> eyre::Result<()> {
         let chars = crate::parser::parse_file(INPUT)?;
-        assert_eq!(score(chars), 157);
+        assert_eq!(score(chars)?, 157);
         Ok(())
     }
+
+    #[test]
+    fn test_not_an_item() {
+        assert_eq!(Err(ItemError::NotALetter('1')), score(vec!['a', '1']));
+        assert_eq!(Ok(Priority(52)), Priority::try_from('Z'));
+        assert_eq!(
+            "'1' isn't an item, those are a to z and A to Z",
+            ItemError::NotALetter('1').to_string()
+        );
+    }
 }
@@ -1,58 +1,55 @@
 use std::collections::HashSet;
 
+use aoc_common::parse::{parse_lines, parse_many, IResult, ParseError};
 use nom::{
-    character::complete::{alpha1, newline},
-    combinator::map,
-    multi::{many0, many1, many_m_n},
-    sequence::terminated,
-    IResult,
+    bytes::complete::take_till1, character::complete::newline, combinator::map_res, error::context,
+    multi::many_m_n, sequence::terminated,
 };
 
+use crate::{ItemError, Priority};
+
+/// Every item has to have a priority
+fn rucksack(input: &str) -> IResult<&str, &str> {
+    let items = context("a rucksack", take_till1(|c: char| c.is_whitespace()));
+    map_res(items, |s: &str| {
+        s.chars()
+            .try_for_each(|c| Priority::try_from(c).map(drop))?;
+        Ok::<_, ItemError>(s)
+    })(input)
+}
+
 fn line(input: &str) -> IResult<&str, HashSet<char>> {
-    map(alpha1, |s: &str| {
+    map_res(rucksack, |s: &str| {
+        if s.len() % 2 == 1 {
+            return Err(ItemError::OddRucksack(s.len()));
+        }
         let (left, right) = s.split_at(s.len() / 2);
         let left_set: HashSet<char> = left.chars().collect();
-        right.chars().filter(|r| left_set.contains(r)).collect()
+        let shared: HashSet<char> = right.chars().filter(|r| left_set.contains(r)).collect();
+        match shared.is_empty() {
+            true => Err(ItemError::NothingShared),
+            false => Ok(shared),
+        }
     })(input)
 }
 
-fn whole_file(input: &str) -> IResult<&str, Vec<HashSet<char>>> {
-    many1(terminated(line, newline))(input)
-}
-
-fn filter_out_badge(groups: Vec<&str>) -> char {
-    *groups
+fn filter_out_badge(groups: Vec<&str>) -> Option<char> {
+    groups
         .into_iter()
         .map(|g| g.chars().collect::<HashSet<_>>())
-        .reduce(|accum, group| {
-            let a = accum.into_iter().filter(|x| group.contains(x)).collect();
-            a
-        })
-        .unwrap()
-        .iter()
+        .reduce(|accum, group| accum.into_iter().filter(|x| group.contains(x)).collect())?
+        .into_iter()
         .next()
-        .unwrap()
 }
 
 fn one_group(input: &str) -> IResult<&str, char> {
-    map(
-        many_m_n(3, 3, terminated(alpha1, newline)),
-        filter_out_badge,
-    )(input)
+    map_res(many_m_n(3, 3, terminated(rucksack, newline)), |group| {
+        filter_out_badge(group).ok_or(ItemError::NoBadge)
+    })(input)
 }
 
-fn groups(input: &str) -> IResult<&str, Vec<char>> {
-    many0(one_group)(input)
-}
-
-pub fn parse_to_groups(contents: &str) -> eyre::Result<Vec<char>> {
-    match groups(contents) {
-        Ok((_, groups)) => Ok(groups),
-        Err(e) => {
-            eprintln!("Error: {e:?}");
-            Err(eyre::eyre!("Went boom: {e:?}"))
-        }
-    }
+pub fn parse_to_groups(contents: &str) -> Result<Vec<char>, ParseError> {
+    parse_many(contents, one_group)
 }
 
 fn flatten(sets: Vec<HashSet<char>>) -> Vec<char> {
@@ -63,14 +60,8 @@ fn flatten(sets: Vec<HashSet<char>>) -> Vec<char> {
     v
 }
 
-pub fn parse_file(contents: &str) -> eyre::Result<Vec<char>> {
-    match whole_file(contents) {
-        Ok((_, sets)) => Ok(flatten(sets)),
-        Err(e) => {
-            eprintln!("Error: {e:?}");
-            Err(eyre::eyre!("Went boom"))
-        }
-    }
+pub fn parse_file(contents: &str) -> Result<Vec<char>, ParseError> {
+    parse_lines(contents, line).map(flatten)
 }
 
 #[cfg(test)]
@@ -94,12 +85,12 @@ mod tests {
             "PmmdzqPrVvPwwTWBwg",
         ];
         let badge = filter_out_badge(groups);
-        assert_eq!('r', badge);
+        assert_eq!(Some('r'), badge);
     }
 
     #[test]
     fn check_groups() -> eyre::Result<()> {
-        let (_, groups) = groups(INPUT)?;
+        let groups = parse_to_groups(INPUT)?;
         assert_eq!(groups.len(), 2);
         Ok(())
     }
@@ -113,7 +104,7 @@ mod tests {
 
     #[test]
     fn lines_all_work() -> eyre::Result<()> {
-        let sets = whole_file(INPUT)?;
+        let sets = parse_lines(INPUT, line)?;
         let expected = vec![
             HashSet::from(['p']),
             HashSet::from(['L']),
@@ -122,7 +113,34 @@ mod tests {
             HashSet::from(['t']),
             HashSet::from(['s']),
         ];
-        assert_eq!(expected, sets.1);
+        assert_eq!(expected, sets);
         Ok(())
     }
+
+    #[test]
+    fn test_short_group() {
+        let err = parse_to_groups("vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdzqPrVvPwwTWBwg\n").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+        assert_eq!("expected a rucksack", err.expected);
+    }
+
+    #[test]
+    fn test_bad_rucksacks() {
+        let err = parse_file("vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdz1PrVvPwwTWBwg\n").unwrap_err();
+        assert_eq!((2, 1), (err.line, err.column));
+        assert_eq!(
+            "'1' isn't an item, those are a to z and A to Z",
+            err.expected
+        );
+
+        let err = parse_file("vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdzqPrVvPwwTWBw\n").unwrap_err();
+        assert_eq!(ItemError::OddRucksack(17).to_string(), err.expected);
+
+        let err = parse_file("abcdABCD\n").unwrap_err();
+        assert_eq!(ItemError::NothingShared.to_string(), err.expected);
+
+        let err = parse_to_groups("abca\nbcdb\nxyzx\n").unwrap_err();
+        assert_eq!((1, 1), (err.line, err.column));
+        assert_eq!(ItemError::NoBadge.to_string(), err.expected);
+    }
 }
@@ -0,0 +1,51 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use eyre::{bail, ensure};
+
+/// Pairs of elves, each with a run of sections to clean
+pub(crate) struct InputGenerator {
+    pairs: usize,
+    sections: u32,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            pairs: 1000,
+            sections: 99,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("pairs", "how many pairs of elves", self.pairs),
+            Param::new("sections", "sections are numbered 1 to this", self.sections),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> eyre::Result<()> {
+        match name {
+            "pairs" => self.pairs = parse_param(name, value)?,
+            "sections" => self.sections = parse_param(name, value)?,
+            _ => bail!("day 4's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> eyre::Result<String> {
+        ensure!(self.pairs > 0, "there has to be at least one pair");
+        ensure!(self.sections > 0, "there has to be at least one section");
+        let mut sections = || {
+            let a = rng.range(1..=self.sections as i64);
+            let b = rng.range(1..=self.sections as i64);
+            format!("{}-{}", a.min(b), a.max(b))
+        };
+        Ok((0..self.pairs)
+            .map(|_| format!("{},{}\n", sections(), sections()))
+            .collect())
+    }
+}
@@ -1,27 +1,76 @@
+mod generate;
 mod parser;
 
-use parser::ContainsExt;
+use std::ops::RangeInclusive;
 
-pub fn run() -> eyre::Result<()> {
-    let input = include_str!("../input.txt");
-    let pairs = parser::parse_file(input)?;
-    let count = pairs
-        .iter()
-        .filter(|(a, b)| a.contains_or_is_contained(b))
-        .count();
+use aoc_common::{generate::Generator, Solution};
+use aoc_interval::IntervalSet;
 
-    let overlaps = pairs
-        .iter()
-        .filter(|(a, b)| a.overlaps_or_is_overlapped(b))
-        .count();
+type Sections = RangeInclusive<u32>;
 
-    println!("Day 4: Part 1: {count}");
-    println!("Day 4: Part 2: {overlaps}");
-    Ok(())
+/// One elf's sections cover all of the other's
+fn one_contains_other((a, b): &(Sections, Sections)) -> bool {
+    IntervalSet::from(a.clone()).contains_range(b) || IntervalSet::from(b.clone()).contains_range(a)
+}
+
+fn overlap((a, b): &(Sections, Sections)) -> bool {
+    IntervalSet::from(a.clone()).overlaps(b)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Input = Vec<(Sections, Sections)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> eyre::Result<Self::Input> {
+        Ok(parser::parse_file(input)?)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> eyre::Result<usize> {
+        Ok(pairs.iter().filter(|pair| one_contains_other(pair)).count())
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> eyre::Result<usize> {
+        Ok(pairs.iter().filter(|pair| overlap(pair)).count())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() -> eyre::Result<()> {
+        let pairs = Day4.parse(INPUT)?;
+        assert_eq!(2, Day4.part1(&pairs)?);
+        assert_eq!(4, Day4.part2(&pairs)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlaps() {
+        assert!(one_contains_other(&((2..=8), (3..=7))));
+        assert!(one_contains_other(&((6..=6), (4..=6))));
+
+        // not overlaps
+        assert!(!one_contains_other(&((2..=4), (6..=8))));
+        assert!(!one_contains_other(&((5..=7), (7..=9))));
+
+        // containment counts as overlapping whichever way round it is
+        assert!(overlap(&((3..=7), (2..=8))));
+        assert!(overlap(&((2..=8), (3..=7))));
+        assert!(!overlap(&((2..=3), (4..=5))));
+    }
+
     pub const INPUT: &str = "2-4,6-8
 2-3,4-5
 5-7,7-9
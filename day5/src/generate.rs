@@ -0,0 +1,75 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+use crate::{write_input, Crate, Dock, Move};
+
+/// Crates piled at random, and moves that only ever take crates a stack
+/// actually has
+pub(crate) struct InputGenerator {
+    stacks: usize,
+    crates: usize,
+    moves: usize,
+    most: usize,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            stacks: 9,
+            crates: 50,
+            moves: 500,
+            most: 20,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("stacks", "how many stacks", self.stacks),
+            Param::new("crates", "how many crates", self.crates),
+            Param::new("moves", "how many moves", self.moves),
+            Param::new("most", "most crates in one move", self.most),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "stacks" => self.stacks = parse_param(name, value)?,
+            "crates" => self.crates = parse_param(name, value)?,
+            "moves" => self.moves = parse_param(name, value)?,
+            "most" => self.most = parse_param(name, value)?,
+            _ => bail!("day 5's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.stacks >= 2, "moves need at least two stacks");
+        ensure!(self.crates > 0, "there has to be at least one crate");
+        ensure!(self.most > 0, "a move takes at least one crate");
+        let mut stacks = vec![Vec::new(); self.stacks];
+        for _ in 0..self.crates {
+            let letter = (b'A' + rng.below(26) as u8) as char;
+            stacks[rng.index(self.stacks)].push(Crate(letter));
+        }
+        let dock = Dock(stacks);
+
+        let mut heights: Vec<usize> = dock.0.iter().map(Vec::len).collect();
+        let moves = (0..self.moves)
+            .map(|_| {
+                let full: Vec<usize> = (0..self.stacks).filter(|&s| heights[s] > 0).collect();
+                let source = *rng.choose(&full);
+                let dest = (source + 1 + rng.index(self.stacks - 1)) % self.stacks;
+                let count = 1 + rng.index(heights[source].min(self.most));
+                heights[source] -= count;
+                heights[dest] += count;
+                Move::new(count, source, dest)
+            })
+            .collect();
+        Ok(write_input(&(dock, moves)))
+    }
+}
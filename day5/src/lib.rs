@@ -0,0 +1,319 @@
+#![allow(dead_code)]
+mod generate;
+mod parser;
+
+use aoc_common::parse::{parse_line, ParseError};
+use aoc_common::{generate::Generator, Solution};
+use itertools::Itertools;
+use std::fmt::Debug;
+use std::{fmt::Display, num::ParseIntError};
+
+use crate::parser::{dock_line, parse_mv};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dock(Vec<Vec<Crate>>);
+
+/// A move the crane can't make with the crates on the dock
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DockError {
+    /// Stacks count from 0
+    NoSuchStack {
+        mv: Move,
+        stack: usize,
+        stacks: usize,
+    },
+    TooFewCrates {
+        mv: Move,
+        has: usize,
+    },
+}
+
+impl Display for DockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DockError::NoSuchStack { mv, stack, stacks } => {
+                write!(f, "{mv}: there's no stack {}, only {stacks}", stack + 1)
+            }
+            DockError::TooFewCrates { mv, has } => {
+                write!(f, "{mv}: stack {} only has {has} crates", mv.source + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DockError {}
+
+impl Dock {
+    /// Checks `mv` can be made before anything is moved, so a bad move
+    /// leaves the dock as it was
+    fn check(&self, mv: Move) -> Result<(), DockError> {
+        for stack in [mv.source, mv.dest] {
+            if stack >= self.0.len() {
+                return Err(DockError::NoSuchStack {
+                    mv,
+                    stack,
+                    stacks: self.0.len(),
+                });
+            }
+        }
+        let has = self.0[mv.source].len();
+        if has < mv.count {
+            return Err(DockError::TooFewCrates { mv, has });
+        }
+        Ok(())
+    }
+
+    pub fn apply_9001(&mut self, mv: Move) -> Result<(), DockError> {
+        self.check(mv)?;
+        let src = &mut self.0[mv.source];
+        let elems = src.split_off(src.len() - mv.count);
+        self.0[mv.dest].extend(elems);
+        Ok(())
+    }
+
+    pub fn apply(&mut self, mv: Move) -> Result<(), DockError> {
+        self.check(mv)?;
+        for _ in 0..mv.count {
+            if let Some(el) = self.0[mv.source].pop() {
+                self.0[mv.dest].push(el);
+            }
+        }
+        Ok(())
+    }
+
+    fn last_crates(&self) -> String {
+        self.0
+            .iter()
+            .filter_map(|s| s.last())
+            .map(|c| c.0)
+            .collect()
+    }
+}
+
+/// The way the puzzle draws it, with the stacks numbered underneath
+impl Display for Dock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self.0.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row = self
+                .0
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c.0),
+                    None => "   ".to_string(),
+                })
+                .join(" ");
+            writeln!(f, "{row}")?;
+        }
+        let numbers = (1..=self.0.len()).map(|n| format!(" {n} ")).join(" ");
+        write!(f, "{numbers}")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Crate(char);
+
+impl Debug for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    count: usize,
+    source: usize,
+    dest: usize,
+}
+
+impl From<(usize, usize, usize)> for Move {
+    fn from((count, source, dest): (usize, usize, usize)) -> Self {
+        Move::new(count, source, dest)
+    }
+}
+
+impl TryFrom<(&str, &str, &str)> for Move {
+    type Error = ParseIntError;
+
+    fn try_from((cnt, src, dst): (&str, &str, &str)) -> Result<Self, Self::Error> {
+        Ok(Move::new(cnt.parse()?, src.parse()?, dst.parse()?))
+    }
+}
+
+/// The way the puzzle writes it, with stacks counting from 1
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.source + 1,
+            self.dest + 1
+        )
+    }
+}
+
+impl Move {
+    pub fn new(count: usize, source: usize, dest: usize) -> Self {
+        Self {
+            source,
+            count,
+            dest,
+        }
+    }
+}
+
+/// Rows of crates from the top down into stacks from the bottom up. A row
+/// that stops short has nothing in the stacks past its end.
+fn transpose_rev<T: Copy>(rows: Vec<Vec<Option<T>>>, stacks: usize) -> Vec<Vec<T>> {
+    let stacks = rows.iter().map(Vec::len).fold(stacks, usize::max);
+    (0..stacks)
+        .map(|i| {
+            rows.iter()
+                .rev()
+                .filter_map(|row| row.get(i).copied().flatten())
+                .collect_vec()
+        })
+        .collect()
+}
+
+fn parse(input: &str) -> Result<(Dock, Vec<Move>), ParseError> {
+    let mut lines = input.lines();
+
+    // the crates stop at the line numbering the stacks
+    let mut crate_lines = Vec::new();
+    let mut stacks = 0;
+    for line in lines.by_ref() {
+        if line.trim_start().starts_with(|c: char| c.is_ascii_digit()) {
+            stacks = line.split_whitespace().count();
+            break;
+        }
+        crate_lines.push(parse_line(input, line, dock_line)?);
+    }
+
+    let crate_cols = transpose_rev(crate_lines, stacks);
+
+    lines.next();
+
+    let moves = lines
+        .map(|line| parse_line(input, line, parse_mv))
+        .try_collect()?;
+
+    Ok((Dock(crate_cols), moves))
+}
+
+/// Back into the puzzle's format, so that `parse` gets the same dock and
+/// moves out of it
+pub fn write_input((dock, moves): &(Dock, Vec<Move>)) -> String {
+    let moves: String = moves.iter().map(|mv| format!("{mv}\n")).collect();
+    format!("{dock}\n\n{moves}")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Input = (Dock, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, (dock, moves): &Self::Input) -> color_eyre::Result<String> {
+        let mut dock = dock.clone();
+        for mv in moves {
+            dock.apply(*mv)?;
+        }
+        Ok(dock.last_crates())
+    }
+
+    /// The CrateMover 9001 moves stacks in one go
+    fn part2(&self, (dock, moves): &Self::Input) -> color_eyre::Result<String> {
+        let mut dock = dock.clone();
+        for mv in moves {
+            dock.apply_9001(*mv)?;
+        }
+        Ok(dock.last_crates())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub const INPUT: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    use super::*;
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let input = Day5.parse(INPUT)?;
+        assert_eq!("CMZ", Day5.part1(&input)?);
+        assert_eq!("MCD", Day5.part2(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_move() {
+        let input = INPUT.replace("move 2 from 2 to 1", "move 2 from 0 to 1");
+        let err = parse(&input).unwrap_err();
+        assert_eq!((8, 13), (err.line, err.column));
+        assert_eq!("stacks count from 1", err.expected);
+    }
+
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        for input in [INPUT, Day5::INPUT] {
+            let parsed = parse(input)?;
+            assert_eq!(input, write_input(&parsed));
+            assert_eq!(parsed, parse(&write_input(&parsed))?);
+        }
+        // emptied stacks still get drawn and numbered
+        let dock = Dock(vec![vec![Crate('A')], vec![], vec![]]);
+        let text = write_input(&(dock.clone(), vec![]));
+        assert_eq!("[A]        \n 1   2   3 \n\n", text);
+        assert_eq!((dock, vec![]), parse(&text)?);
+        let empty = (Dock(vec![vec![], vec![]]), vec![Move::new(1, 0, 1)]);
+        assert_eq!(empty, parse(&write_input(&empty))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_impossible_moves() -> color_eyre::Result<()> {
+        let (mut dock, _) = parse(INPUT)?;
+        let mv = Move::new(4, 1, 0);
+        assert_eq!(Err(DockError::TooFewCrates { mv, has: 3 }), dock.apply(mv));
+        assert_eq!("NDP", dock.last_crates());
+        let mv = Move::new(1, 3, 0);
+        assert_eq!(
+            Err(DockError::NoSuchStack {
+                mv,
+                stack: 3,
+                stacks: 3
+            }),
+            dock.apply_9001(mv)
+        );
+
+        let input = Day5.parse(&INPUT.replace("move 2 from 2 to 1", "move 3 from 2 to 1"))?;
+        assert_eq!(
+            "move 3 from 2 to 1: stack 2 only has 2 crates",
+            Day5.part1(&input).unwrap_err().to_string()
+        );
+        Ok(())
+    }
+}
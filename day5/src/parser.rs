@@ -4,15 +4,17 @@ use crate::Move;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 
+use aoc_common::parse::tag;
+use aoc_common::parse::IResult;
 use color_eyre::eyre;
 use nom::branch::alt;
-use nom::bytes::complete::tag;
 use nom::character::complete::anychar;
 use nom::character::complete::char;
 use nom::character::complete::digit1;
 use nom::character::complete::newline;
 use nom::character::complete::one_of;
 use nom::character::complete::u32;
+use nom::combinator::cut;
 use nom::combinator::map;
 use nom::combinator::map_res;
 use nom::combinator::opt;
@@ -23,12 +25,13 @@ use nom::sequence::preceded;
 use nom::sequence::separated_pair;
 use nom::sequence::terminated;
 use nom::sequence::tuple;
-use nom::IResult;
 
 use crate::Crate;
 
 fn crate_there(input: &str) -> IResult<&str, Option<Crate>> {
-    map(delimited(tag("["), anychar, tag("]")), |c| Some(Crate(c)))(input)
+    map(delimited(tag("["), anychar, cut(tag("]"))), |c| {
+        Some(Crate(c))
+    })(input)
 }
 fn crate_missing(input: &str) -> IResult<&str, Option<Crate>> {
     map(tag("   "), |_| None)(input)
@@ -46,7 +49,7 @@ fn num(input: &str) -> IResult<&str, usize> {
     map(u32, |n| n as _)(input)
 }
 fn pile_num(input: &str) -> IResult<&str, usize> {
-    map(num, |n| n - 1)(input)
+    map_res(num, |n| n.checked_sub(1).ok_or("stacks count from 1"))(input)
 }
 
 pub fn parse_mv(input: &str) -> IResult<&str, Move> {
@@ -71,7 +74,8 @@ mod tests {
     fn test_parse_move() -> color_eyre::Result<()> {
         let input = "move 1 from 2 to 1\n";
         let x = parse_mv(input)?;
-        assert_eq!(Move::new(1, 2, 1), x.1);
+        // piles are zero-indexed once parsed
+        assert_eq!(Move::new(1, 1, 0), x.1);
         Ok(())
     }
 }
@@ -0,0 +1,98 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const MARKER: usize = 14;
+
+/// A datastream ending in a start-of-message marker. Everything before it
+/// is drawn from the first `letters` letters, so with fewer than 4 neither
+/// marker turns up until the very end.
+pub(crate) struct InputGenerator {
+    length: usize,
+    letters: usize,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            length: 4096,
+            letters: 26,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("length", "how long the datastream is", self.length),
+            Param::new(
+                "letters",
+                "how many letters come before the end",
+                self.letters,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "length" => self.length = parse_param(name, value)?,
+            "letters" => self.letters = parse_param(name, value)?,
+            _ => bail!("day 6's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(
+            self.length > MARKER,
+            "the datastream needs room for a {MARKER} letter marker"
+        );
+        ensure!(
+            (1..=ALPHABET.len()).contains(&self.letters),
+            "there are only {} letters",
+            ALPHABET.len()
+        );
+        let mut stream: Vec<u8> = (0..self.length - MARKER)
+            .map(|_| *rng.choose(&ALPHABET[..self.letters]))
+            .collect();
+
+        // starting the marker with the letter before it means no window
+        // that only partly overlaps it can be a marker either
+        let last = *stream.last().unwrap();
+        let mut rest: Vec<u8> = ALPHABET.iter().copied().filter(|&c| c != last).collect();
+        rng.shuffle(&mut rest);
+        stream.push(last);
+        stream.extend(&rest[..MARKER - 1]);
+        stream.push(b'\n');
+        Ok(String::from_utf8(stream)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day6;
+
+    #[test]
+    fn test_generated() -> color_eyre::Result<()> {
+        for seed in 0..5 {
+            let generator = InputGenerator {
+                length: 500,
+                letters: 1 + seed as usize % 3,
+            };
+            let input = generator.generate(&mut Rng::new(seed))?;
+            let stream = Day6.parse(&input)?;
+            assert_eq!(500, stream.len());
+            assert_eq!(490, Day6.part1(&stream)?);
+            assert_eq!(500, Day6.part2(&stream)?);
+        }
+        let stream = Day6.parse(&InputGenerator::default().generate(&mut Rng::new(0))?)?;
+        assert!(Day6.part1(&stream)? < Day6.part2(&stream)?);
+        Ok(())
+    }
+}
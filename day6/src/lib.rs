@@ -1,5 +1,9 @@
+mod generate;
+
 use std::{collections::HashSet, hash::Hash};
 
+use aoc_common::{generate::Generator, Solution};
+use color_eyre::eyre::eyre;
 use itertools::Itertools;
 
 fn is_unique<T>(a: T, b: T, c: T, d: T) -> bool
@@ -27,10 +31,31 @@ fn start_message(input: &str) -> Option<usize> {
         .map(|pos| pos + SEQ_SIZE)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    println!("Index: {:?}", start_index(input));
-    println!("Message: {:?}", start_message(input));
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("../input.txt");
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<String> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(&self, input: &String) -> color_eyre::Result<usize> {
+        start_index(input).ok_or_else(|| eyre!("no start-of-packet marker"))
+    }
+
+    fn part2(&self, input: &String) -> color_eyre::Result<usize> {
+        start_message(input).ok_or_else(|| eyre!("no start-of-message marker"))
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
 }
 
 #[cfg(test)]
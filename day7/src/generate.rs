@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+/// A terminal session that `ls`es every directory of a random tree once,
+/// depth first
+pub(crate) struct InputGenerator {
+    dirs: usize,
+    files: usize,
+    size: u64,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            dirs: 200,
+            files: 400,
+            size: 300_000,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Dir {
+    name: String,
+    dirs: Vec<usize>,
+    files: Vec<(u64, String)>,
+}
+
+fn name(rng: &mut Rng, taken: &[String]) -> String {
+    loop {
+        let len = 1 + rng.index(8);
+        let name: String = (0..len)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+fn session(dirs: &[Dir], dir: usize, out: &mut String) {
+    writeln!(out, "$ cd {}\n$ ls", dirs[dir].name).unwrap();
+    for &child in &dirs[dir].dirs {
+        writeln!(out, "dir {}", dirs[child].name).unwrap();
+    }
+    for (size, name) in &dirs[dir].files {
+        writeln!(out, "{size} {name}").unwrap();
+    }
+    for &child in &dirs[dir].dirs {
+        session(dirs, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("dirs", "how many directories below /", self.dirs),
+            Param::new("files", "how many files", self.files),
+            Param::new("size", "biggest a file gets", self.size),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "dirs" => self.dirs = parse_param(name, value)?,
+            "files" => self.files = parse_param(name, value)?,
+            "size" => self.size = parse_param(name, value)?,
+            _ => bail!("day 7's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.files > 0, "there has to be at least one file");
+        ensure!(self.size > 0, "files are at least 1 byte");
+        let mut dirs = vec![Dir {
+            name: "/".to_string(),
+            ..Dir::default()
+        }];
+        let taken = |dirs: &[Dir], parent: usize| -> Vec<String> {
+            let dir = &dirs[parent];
+            let subdirs = dir.dirs.iter().map(|&d| dirs[d].name.clone());
+            subdirs
+                .chain(dir.files.iter().map(|f| f.1.clone()))
+                .collect()
+        };
+        for _ in 0..self.dirs {
+            let parent = rng.index(dirs.len());
+            let name = name(rng, &taken(&dirs, parent));
+            let id = dirs.len();
+            dirs[parent].dirs.push(id);
+            dirs.push(Dir {
+                name,
+                ..Dir::default()
+            });
+        }
+        for _ in 0..self.files {
+            let dir = rng.index(dirs.len());
+            let name = name(rng, &taken(&dirs, dir));
+            let size = rng.range(1..=self.size as i64) as u64;
+            dirs[dir].files.push((size, name));
+        }
+        let mut out = String::new();
+        session(&dirs, 0, &mut out);
+        Ok(out)
+    }
+}
@@ -0,0 +1,297 @@
+#![allow(unused)]
+
+mod generate;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use aoc_common::parse::{parse_lines, tag, IResult};
+use aoc_common::{generate::Generator, parse_param, Param, Solution};
+use camino::Utf8PathBuf;
+use color_eyre::eyre::eyre;
+use id_tree::{InsertBehavior, Node, Tree, TreeBuilder};
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    combinator::map,
+    sequence::{preceded, separated_pair},
+};
+
+#[derive(Debug)]
+struct Ls;
+
+fn parse_ls(i: &str) -> IResult<&str, Ls> {
+    map(tag("ls"), |_| Ls)(i)
+}
+
+#[derive(Debug)]
+struct Cd(Utf8PathBuf);
+
+fn parse_path(i: &str) -> IResult<&str, Utf8PathBuf> {
+    map(
+        take_while1(|c: char| "abcdefghijklmnopqrstuvwxyz./".contains(c)),
+        Into::into,
+    )(i)
+}
+
+fn parse_cd(i: &str) -> IResult<&str, Cd> {
+    map(preceded(tag("cd "), parse_path), Cd)(i)
+}
+
+#[derive(Debug)]
+enum Command {
+    Ls,
+    Cd(Utf8PathBuf),
+}
+
+impl From<Ls> for Command {
+    fn from(_: Ls) -> Self {
+        Command::Ls
+    }
+}
+
+impl From<Cd> for Command {
+    fn from(cd: Cd) -> Self {
+        Self::Cd(cd.0)
+    }
+}
+
+fn parse_command(i: &str) -> IResult<&str, Command> {
+    preceded(
+        tag("$ "),
+        alt((map(parse_cd, Into::into), map(parse_ls, Into::into))),
+    )(i)
+}
+
+#[derive(Debug)]
+enum Entry {
+    Dir(Utf8PathBuf),
+    File(u64, Utf8PathBuf),
+}
+
+fn parse_entry(i: &str) -> IResult<&str, Entry> {
+    let parse_file = map(
+        separated_pair(nom::character::complete::u64, tag(" "), parse_path),
+        |(s, n)| Entry::File(s, n),
+    );
+
+    let parse_dir = map(preceded(tag("dir "), parse_path), Entry::Dir);
+
+    alt((parse_dir, parse_file))(i)
+}
+
+#[derive(Debug)]
+enum Line {
+    Command(Command),
+    Entry(Entry),
+}
+
+fn parse_line(i: &str) -> IResult<&str, Line> {
+    alt((
+        map(parse_command, Line::Command),
+        map(parse_entry, Line::Entry),
+    ))(i)
+}
+
+#[derive(Debug, Default)]
+pub struct FsEntry {
+    path: Utf8PathBuf,
+    size: u64,
+}
+
+impl FsEntry {
+    fn new(size: u64, path: Utf8PathBuf) -> Self {
+        Self { size, path }
+    }
+
+    fn root() -> Self {
+        Self::new(0, "/".into())
+    }
+}
+
+fn total_size(tree: &Tree<FsEntry>, node: &Node<FsEntry>) -> color_eyre::Result<u64> {
+    let mut total = node.data().size;
+    for child in node.children() {
+        total += total_size(tree, tree.get(child)?)?;
+    }
+    Ok(total)
+}
+
+fn build_tree(input: &str) -> color_eyre::Result<Tree<FsEntry>> {
+    let lines = parse_lines(input, parse_line)?;
+
+    let mut tree: Tree<FsEntry> = TreeBuilder::new().with_node_capacity(5).build();
+    let root_id = tree.insert(Node::new(FsEntry::root()), InsertBehavior::AsRoot)?;
+    let mut cwd = root_id;
+    for line in lines {
+        match line {
+            Line::Command(cmd) => match cmd {
+                Command::Ls => (),
+                Command::Cd(dir) => match dir.as_str() {
+                    "/" => {}
+                    ".." => {
+                        let parent = tree.get(&cwd)?.parent();
+                        cwd = parent.ok_or_else(|| eyre!("cd .. from the root"))?.clone();
+                    }
+                    _ => {
+                        let node = Node::new(FsEntry::new(0, dir));
+                        cwd = tree.insert(node, InsertBehavior::UnderNode(&cwd))?;
+                    }
+                },
+            },
+            Line::Entry(entry) => match entry {
+                Entry::Dir(dir) => {}
+                Entry::File(size, name) => {
+                    let node = Node::new(FsEntry::new(size, name));
+                    tree.insert(node, InsertBehavior::UnderNode(&cwd))?;
+                }
+            },
+        }
+    }
+    Ok(tree)
+}
+
+fn dir_sizes(tree: &Tree<FsEntry>) -> color_eyre::Result<Vec<u64>> {
+    tree.traverse_pre_order(tree.root_node_id().unwrap())?
+        .filter(|n| !n.children().is_empty())
+        .map(|n| total_size(tree, n))
+        .collect()
+}
+
+pub struct Day7 {
+    pub small_dir: u64,
+    pub disk_size: u64,
+    pub space_needed: u64,
+}
+
+impl Default for Day7 {
+    fn default() -> Self {
+        Self {
+            small_dir: 100_000,
+            disk_size: 70_000_000,
+            space_needed: 30_000_000,
+        }
+    }
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const SAMPLE: Option<&'static str> = Some(INPUT);
+
+    type Input = Tree<FsEntry>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Tree<FsEntry>> {
+        build_tree(input)
+    }
+
+    fn part1(&self, tree: &Tree<FsEntry>) -> color_eyre::Result<u64> {
+        Ok(dir_sizes(tree)?
+            .into_iter()
+            .filter(|&s| s <= self.small_dir)
+            .sum())
+    }
+
+    fn part2(&self, tree: &Tree<FsEntry>) -> color_eyre::Result<u64> {
+        let used = total_size(tree, tree.get(tree.root_node_id().unwrap())?)?;
+        let free_space = self.disk_size.saturating_sub(used);
+        let min_delta = self.space_needed.saturating_sub(free_space);
+
+        dir_sizes(tree)?
+            .into_iter()
+            .filter(|&s| s > min_delta)
+            .min()
+            .ok_or_else(|| color_eyre::eyre::eyre!("no directory is big enough"))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new(
+                "small_dir",
+                "part 1 sums directories up to this size",
+                self.small_dir,
+            ),
+            Param::new("disk_size", "size of the whole disk", self.disk_size),
+            Param::new(
+                "space_needed",
+                "free space the update needs",
+                self.space_needed,
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "small_dir" => self.small_dir = parse_param(name, value)?,
+            "disk_size" => self.disk_size = parse_param(name, value)?,
+            "space_needed" => self.space_needed = parse_param(name, value)?,
+            _ => color_eyre::eyre::bail!("day 7 has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+const INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let day = Day7::default();
+        let tree = day.parse(INPUT)?;
+        assert_eq!(95437, day.part1(&tree)?);
+        assert_eq!(24933642, day.part2(&tree)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bad_command() {
+        let Err(err) = build_tree("$ cd /\n$ rm a.txt\n") else {
+            panic!("rm isn't a command");
+        };
+        assert_eq!(
+            "line 2, column 3: expected \"cd \" or \"ls\"\n 2 | $ rm a.txt\n   |   ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_above_the_root() {
+        let Err(err) = build_tree("$ cd /\n$ cd ..\n") else {
+            panic!("the root has no parent");
+        };
+        assert_eq!("cd .. from the root", err.to_string());
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+/// A forest of random tree heights
+pub(crate) struct InputGenerator {
+    rows: usize,
+    cols: usize,
+    tallest: u8,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            rows: 99,
+            cols: 99,
+            tallest: 9,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("rows", "how many rows of trees", self.rows),
+            Param::new("cols", "how many columns of trees", self.cols),
+            Param::new("tallest", "tallest a tree gets, at most 9", self.tallest),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "rows" => self.rows = parse_param(name, value)?,
+            "cols" => self.cols = parse_param(name, value)?,
+            "tallest" => self.tallest = parse_param(name, value)?,
+            _ => bail!("day 8's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.rows > 0 && self.cols > 0, "the forest can't be empty");
+        ensure!(self.tallest <= 9, "tree heights are one digit");
+        let mut out = String::with_capacity(self.rows * (self.cols + 1));
+        for _ in 0..self.rows {
+            for _ in 0..self.cols {
+                out.push((b'0' + rng.below(self.tallest as u64 + 1) as u8) as char);
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,127 @@
+mod generate;
+
+use aoc_common::{generate::Generator, Solution};
+use aoc_geometry::Direction;
+use aoc_grid::{Grid, Pos};
+use color_eyre::eyre::eyre;
+
+const INPUT: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+fn parse_grid(input: &str) -> color_eyre::Result<Grid<u8>> {
+    Ok(Grid::parse_with(input, |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })?)
+}
+
+fn is_tree_visible<T: PartialOrd>(grid: &Grid<T>, row: usize, col: usize) -> bool {
+    let pos = Pos::new(row, col);
+    let tree = &grid[pos];
+    Direction::ALL
+        .into_iter()
+        .any(|direction| grid.ray(pos, direction).all(|(_, other)| tree > other))
+}
+
+/// Trees seen before one at least as tall blocks the view, that one included
+fn viewing_distance(grid: &Grid<u8>, pos: Pos, direction: Direction) -> u64 {
+    let tree = grid[pos];
+    let mut total = 0;
+    for (_, &other) in grid.ray(pos, direction) {
+        total += 1;
+        if tree <= other {
+            break;
+        }
+    }
+    total
+}
+
+fn tree_scenic_score(grid: &Grid<u8>, row: usize, col: usize) -> u64 {
+    let pos = Pos::new(row, col);
+    Direction::ALL
+        .into_iter()
+        .map(|direction| viewing_distance(grid, pos, direction))
+        .product()
+}
+
+fn count_visible_trees<T>(grid: &Grid<T>) -> usize
+where
+    T: PartialOrd,
+{
+    grid.positions()
+        .filter(|pos| is_tree_visible(grid, pos.row, pos.col))
+        .count()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const SAMPLE: Option<&'static str> = Some(INPUT);
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Grid<u8>> {
+        parse_grid(input)
+    }
+
+    fn part1(&self, grid: &Grid<u8>) -> color_eyre::Result<usize> {
+        Ok(count_visible_trees(grid))
+    }
+
+    fn part2(&self, grid: &Grid<u8>) -> color_eyre::Result<u64> {
+        grid.positions()
+            .map(|pos| tree_scenic_score(grid, pos.row, pos.col))
+            .max()
+            .ok_or_else(|| eyre!("grid has no trees"))
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let grid = Day8.parse(INPUT)?;
+        assert_eq!(21, Day8.part1(&grid)?);
+        assert_eq!(8, Day8.part2(&grid)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_schenic_scores() -> color_eyre::Result<()> {
+        let grid = parse_grid(INPUT)?;
+        assert_eq!(4, tree_scenic_score(&grid, 1, 2));
+        assert_eq!(8, tree_scenic_score(&grid, 3, 2));
+        assert_eq!(0, tree_scenic_score(&grid, 0, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_visible() -> color_eyre::Result<()> {
+        let grid = parse_grid(INPUT)?;
+        assert_eq!(INPUT, grid.to_string());
+        assert!(is_tree_visible(&grid, 1, 1));
+        assert!(is_tree_visible(&grid, 1, 2));
+        assert!(!is_tree_visible(&grid, 1, 3));
+        assert!(is_tree_visible(&grid, 2, 1));
+        assert!(!is_tree_visible(&grid, 2, 2));
+        assert!(is_tree_visible(&grid, 2, 3));
+        assert!(!is_tree_visible(&grid, 3, 1));
+        assert!(is_tree_visible(&grid, 3, 2));
+        assert!(!is_tree_visible(&grid, 3, 3));
+        assert!(is_tree_visible(&grid, 0, 0));
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::{
+    generate::{Generator, Rng},
+    parse_param, Param,
+};
+use color_eyre::eyre::{bail, ensure};
+
+/// The head of the rope wandering about at random
+pub(crate) struct InputGenerator {
+    moves: usize,
+    steps: usize,
+}
+
+impl Default for InputGenerator {
+    fn default() -> Self {
+        Self {
+            moves: 2000,
+            steps: 20,
+        }
+    }
+}
+
+impl Generator for InputGenerator {
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param::new("moves", "how many moves", self.moves),
+            Param::new("steps", "most steps in one move", self.steps),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "moves" => self.moves = parse_param(name, value)?,
+            "steps" => self.steps = parse_param(name, value)?,
+            _ => bail!("day 9's generator has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut Rng) -> color_eyre::Result<String> {
+        ensure!(self.steps > 0, "a move is at least one step");
+        Ok((0..self.moves)
+            .map(|_| {
+                let dir = rng.choose(&['U', 'D', 'L', 'R']);
+                format!("{dir} {}\n", 1 + rng.index(self.steps))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,214 @@
+mod generate;
+mod parser;
+
+use std::{collections::HashSet, fmt::Debug};
+
+use aoc_common::{generate::Generator, parse_param, Param, Solution};
+use aoc_geometry::{Direction, Vec2};
+use color_eyre::eyre::bail;
+use itertools::Itertools;
+
+#[allow(dead_code)]
+const INPUT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+type Point = Vec2<i32>;
+
+fn point(x: i32, y: i32) -> Point {
+    Vec2::new(x, y)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    dir: Direction,
+    count: usize,
+}
+
+fn parse_moves(input: &str) -> color_eyre::Result<Vec<Move>> {
+    Ok(parser::moves(input)?)
+}
+
+fn tail_locations(moves: &[Move], knots: usize) -> usize {
+    let mut engine = Engine::new(knots);
+    for mv in moves {
+        engine.apply(*mv);
+    }
+    engine.count_tail_locations()
+}
+
+pub struct Day9 {
+    /// Part 1 is always a two knot rope
+    pub knots: usize,
+}
+
+impl Default for Day9 {
+    fn default() -> Self {
+        Self { knots: 10 }
+    }
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("input.txt");
+    const SAMPLE: Option<&'static str> = Some(INPUT);
+
+    type Input = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> color_eyre::Result<Vec<Move>> {
+        parse_moves(input)
+    }
+
+    fn part1(&self, moves: &Vec<Move>) -> color_eyre::Result<usize> {
+        Ok(tail_locations(moves, 2))
+    }
+
+    fn part2(&self, moves: &Vec<Move>) -> color_eyre::Result<usize> {
+        Ok(tail_locations(moves, self.knots))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param::new("knots", "knots in the part 2 rope", self.knots)]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> color_eyre::Result<()> {
+        match name {
+            "knots" => match parse_param(name, value)? {
+                0 => bail!("the rope needs at least one knot"),
+                knots => self.knots = knots,
+            },
+            _ => bail!("day 9 has no parameter {name:?}"),
+        }
+        Ok(())
+    }
+
+    fn generator(&self) -> Option<Box<dyn Generator>> {
+        Some(Box::<generate::InputGenerator>::default())
+    }
+}
+
+fn is_touching(a: Point, b: Point) -> bool {
+    a.chebyshev(b) <= 1
+}
+
+struct Engine {
+    rope: Vec<Point>,
+    tail_locations: HashSet<Point>,
+}
+
+impl Debug for Engine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Engine")
+            .field("rope", &self.rope.iter().map(|p| (p.x, p.y)).collect_vec())
+            .field(
+                "tail_locations",
+                &self.tail_locations.iter().map(|p| (p.x, p.y)).collect_vec(),
+            )
+            .finish()
+    }
+}
+
+impl Engine {
+    fn new(knots: usize) -> Self {
+        Self {
+            rope: vec![point(0, 0); knots],
+            tail_locations: HashSet::from([point(0, 0)]),
+        }
+    }
+    fn apply(&mut self, mv: Move) {
+        // println!("Move: {:?}", mv);
+        let delta: Point = mv.dir.delta();
+        for _ in 0..mv.count {
+            self.rope[0] += delta;
+            for i in 1..self.rope.len() {
+                let diff = self.rope[i - 1] - self.rope[i];
+                // a knot that's fallen behind moves one step straight or
+                // diagonally towards the one in front
+                if !is_touching(self.rope[i - 1], self.rope[i]) {
+                    self.rope[i] += diff.signum();
+                }
+            }
+            self.tail_locations.insert(*self.rope.last().unwrap());
+        }
+
+        // println!("{:?}", self);
+    }
+
+    fn count_tail_locations(&self) -> usize {
+        self.tail_locations.iter().count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROPE2: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn test_rope_2() -> color_eyre::Result<()> {
+        let moves = parse_moves(ROPE2)?;
+
+        let mut engine = Engine::new(10);
+        println!("{:?}", engine);
+        for mv in moves {
+            println!("{:?}", &mv);
+            engine.apply(mv);
+            println!("{:?}", &engine);
+        }
+        println!("{:?}", engine);
+
+        assert_eq!(36, engine.count_tail_locations());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_count() -> color_eyre::Result<()> {
+        let moves = parse_moves(INPUT)?;
+
+        let mut engine = Engine::new(10);
+        println!("{:?}", engine);
+        for mv in moves {
+            println!("{:?}", &mv);
+            engine.apply(mv);
+            println!("{:?}", &engine);
+        }
+        println!("{:?}", engine);
+
+        assert_eq!(1, engine.count_tail_locations());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample() -> color_eyre::Result<()> {
+        let day = Day9::default();
+        let moves = day.parse(INPUT)?;
+        assert_eq!(13, day.part1(&moves)?);
+        assert_eq!(1, day.part2(&moves)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_touching() {
+        assert!(is_touching(point(1, 1), point(0, 0)));
+        assert!(!is_touching(point(4, 0), point(0, 0)));
+    }
+}
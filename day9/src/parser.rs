@@ -0,0 +1,54 @@
+use aoc_common::parse::{parse_lines, tag, IResult, ParseError};
+use aoc_geometry::Direction;
+use nom::{
+    branch::alt,
+    character::complete::u32,
+    combinator::{map, value},
+    sequence::separated_pair,
+};
+
+use crate::Move;
+
+fn direction(i: &str) -> IResult<&str, Direction> {
+    alt((
+        value(Direction::Up, tag("U")),
+        value(Direction::Right, tag("R")),
+        value(Direction::Down, tag("D")),
+        value(Direction::Left, tag("L")),
+    ))(i)
+}
+
+/// `R 4`
+fn head_move(i: &str) -> IResult<&str, Move> {
+    map(separated_pair(direction, tag(" "), u32), |(dir, count)| {
+        Move {
+            dir,
+            count: count as usize,
+        }
+    })(i)
+}
+
+pub(crate) fn moves(input: &str) -> Result<Vec<Move>, ParseError> {
+    parse_lines(input, head_move)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move() {
+        let (_, mv) = head_move("L 12").unwrap();
+        assert_eq!((Direction::Left, 12), (mv.dir, mv.count));
+    }
+
+    #[test]
+    fn test_bad_line() {
+        let err = moves("R 4\nU 4\nX 3\n").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+
+        let err = moves("R 4\nU four\n").unwrap_err();
+        assert_eq!((2, 3), (err.line, err.column));
+        assert_eq!("expected a number", err.expected);
+    }
+}